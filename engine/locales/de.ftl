@@ -0,0 +1,236 @@
+## Ablauf
+
+prompt-ready = Bereit...
+prompt-listening = Höre zu...
+prompt-awaiting-answer = Welches Zeichen ?
+prompt-wrong =
+    Erwartet: { $expected }
+    Deine Antwort: { $typed }
+
+    Wiederhole { $repeats }x...
+prompt-skipped = Übersprungen: { $expected }
+prompt-repeat-solution =
+    Lösung: { $name }
+
+    Wiederholung { $index }/{ $total }
+prompt-repeat-typed =
+    Deine Antwort: { $name }
+
+    Wiederholung { $index }/{ $total }
+prompt-pause = Pause...
+prompt-stopped = Gestoppt
+prompt-nothing-selected = Keine Zeichen ausgewählt
+prompt-time-up = Zeit abgelaufen
+prompt-items-done = Alle Zeichen gesendet
+prompt-target-reached = Ziel-Trefferquote erreicht
+prompt-playback-failed =
+    Kann { $name } nicht abspielen:
+    { $error }
+prompt-copy-check = Was wurde gesendet?
+prompt-text-finished = Text zu Ende
+prompt-streaming =
+    Mitschreiben: { $sent } Zeichen gesendet
+
+    { $typed }
+prompt-stream-result =
+    Gesendet: { $sent }
+    Getippt:  { $typed }
+
+    Richtig: { $correct } von { $total } (Fehler: { $errors })
+pause-overlay =
+    Pausiert
+
+    Weiter mit { $key }
+score = Richtig: { $correct } | Falsch: { $wrong }
+
+## Zeichenauswahl
+
+preset-all = Alle
+preset-letters = Buchstaben
+preset-digits = Ziffern
+preset-punctuation = Satzzeichen
+preset-prosigns = Betriebsz.
+preset-hard-letters = YQZJX
+preset-custom = Eigene
+drill-random = Zufall
+drill-confusables = Verwechsler
+drill-copy-behind = Mitschreiben
+char-select-count = Zeichenauswahl: { $selected } von { $total } ausgewählt
+drill-random-info = Zufällige Zeichen aus der Auswahl
+drill-copy-behind-info = Zeichen kommen ohne Pause für Antworten, einfach hinterher mitschreiben
+drill-groups = Gruppen: { $groups }
+
+## Buttons
+
+button-start = Start
+button-pause = Pause
+button-resume = Weiter
+button-stop = Stopp
+button-characters = Zeichen
+button-submit = Bestätigen
+button-replay = Nochmal
+button-heatmap = Fehlerbild
+button-stats = Statistik
+button-settings = Einstellungen
+button-back = Zurück
+button-done = Fertig
+button-export = Export
+button-save = Speichern
+button-continue = Weiter
+button-profile = Profil
+button-create = Anlegen
+button-keys = Tasten
+
+## Fehlerbild und Statistik
+
+heatmap-title = Verwechslungen ({ $profile }): Zeile = gesendet, Spalte = getippt
+heatmap-empty = Noch keine Fehler aufgezeichnet.
+heatmap-top = Häufigste: { $confusions }
+stats-summary = Statistik ({ $profile }): { $answers } Antworten, { $accuracy }% richtig, { $replays }x nochmal gehört, { $minutes } min | Serie: { $streak } Tage (Rekord { $best })
+stats-daily-accuracy = Trefferquote pro Tag (%)
+stats-daily-minutes = Minuten pro Tag
+stats-per-character = Trefferquote und Median-Reaktionszeit pro Zeichen
+stats-no-data = Noch keine Daten
+export-saved =
+    Gespeichert:
+    { $csv }
+    { $json }
+export-failed = Export fehlgeschlagen: { $error }
+
+## Sitzung
+
+home-title = Neue Sitzung
+home-profile = Profil: { $profile }
+profile-title = Profil wählen
+profile-new = Neues Profil:
+home-limits = Sitzungsende: { $limits }
+home-unlimited = Die Sitzung läuft, bis sie gestoppt wird
+limit-minutes = { $minutes } min
+limit-items = { $items } Zeichen
+limit-target = { $percent } % Trefferquote
+limit-or = { " " }oder{ " " }
+summary-title = Sitzung beendet
+summary-duration = Dauer: { $minutes } min
+summary-no-answers = Keine Antworten
+summary-accuracy = Trefferquote: { $accuracy } % ({ $correct } von { $total })
+summary-latency = Reaktionszeit (Median): { $ms } ms
+summary-replays = Nochmal angehört: { $replays }x
+summary-weakest = Schwächste Zeichen: { $characters }
+summary-previous = Letzte Sitzung: { $accuracy } % ({ $delta } Prozentpunkte)
+summary-previous-latency = Reaktionszeit letzte Sitzung: { $ms } ms ({ $delta } ms)
+summary-first = Erste Sitzung, noch kein Vergleich
+
+## Einstellungen
+
+settings-title = Einstellungen
+setting-pause = Pause nach einer Runde
+setting-repeat-pause = Pause zwischen Wiederholungen
+setting-repeats = Wiederholungen nach Fehler
+setting-lag = Mitschreiben: erlaubter Rückstand
+setting-gap = Mitschreiben: Pause zwischen Zeichen
+setting-words-per-chunk = Text: Wörter am Stück
+setting-unknown = Text: unbekannte Zeichen
+setting-copy-check = Text: Mitgeschriebenes abfragen
+setting-session-minutes = Sitzung: Dauer
+setting-session-items = Sitzung: Anzahl Zeichen
+setting-target-accuracy = Sitzung: Ziel-Trefferquote
+setting-replay-penalty = Sitzung: Abzug nach Nochmal
+policy-skip = weglassen
+policy-substitute = ersetzen
+policy-spell = ausschreiben
+on = an
+off = aus
+setting-language = Sprache (nach Neustart)
+setting-large-font = Große Schrift
+setting-fullscreen = Vollbild (F11)
+setting-compact = Kompakte Ansicht
+setting-accessible = Rückmeldung per Ton
+setting-answer-back = Lösung danach
+answer-back-off = aus
+answer-back-morse = in Morse
+answer-back-speech = gesprochen
+a11y-correct = Richtig: { $answer }
+a11y-wrong = Falsch, richtig war { $answer }
+language-system = System
+settings-saved = Gespeichert: { $path }
+settings-not-saved = Nicht gespeichert: { $error }
+key-bindings-title = Tastenbelegung
+key-start-stop = Sitzung starten / beenden
+key-pause = Pause / weiter
+key-replay = Zeichen nochmal abspielen
+key-skip = Zeichen überspringen
+key-press = Taste drücken...
+key-bindings-hint = Belegbar sind F1 bis F10, F12, die Pfeiltasten und Einfg bis Bild ab. Escape bricht ab.
+key-not-allowed = { $key } ist nicht belegbar
+hotkeys-hint = { $start_stop } Stopp | { $pause } Pause | { $replay } Nochmal | { $skip } Weiter
+
+## Terminal
+
+tui-status = Status
+tui-status-paused = Status (pausiert)
+tui-answer = Antwort
+tui-home = { $key } startet eine neue Sitzung.
+tui-help = { $start_stop } Start/Stopp | { $pause } Pause/Weiter | { $replay } Nochmal | { $skip } Überspringen | Tab Mitschreiben an/aus | Enter Bestätigen | Esc Beenden
+tui-usage = Aufruf: cw-listen-tui [--audio rodio|loopback|silent]
+tui-missing-value = --audio braucht einen Wert
+tui-unknown-argument = Unbekanntes Argument: { $argument }
+tui-unknown-backend = Unbekannte Audio-Ausgabe: { $backend }
+tui-no-audio = Keine Audio-Ausgabe verfügbar ({ $error }), evtl. mit --audio loopback starten
+
+## Kommandozeile und Fehler
+
+error = Fehler: { $error }
+warning-no-audio = Warnung: Keine Audio-Ausgabe ({ $error }), die Zeichen laufen stumm in ihrer normalen Länge
+warning-lesson-missing = Warnung: Nicht vorhanden und ausgelassen: { $characters }
+audio-error = Audio-Fehler: { $error }
+cli-unknown-characters = Unbekannte Zeichen: { $characters }
+cli-invalid-duration = --duration muss größer als 0 sein, nicht { $value }
+cli-invalid-wpm = --wpm muss zwischen 5 und 60 liegen, nicht { $value }
+cli-invalid-farnsworth = --farnsworth muss zwischen 0 und --wpm ({ $wpm }) liegen, nicht { $value }
+cli-invalid-record-format = --record braucht eine Datei mit Endung .wav, .flac oder .ogg, nicht { $path }
+record-saved = Aufnahme gespeichert: { $audio }, Lösungen: { $key }
+record-speed = Tempo: { $wpm } WpM
+record-speed-farnsworth = Tempo: { $wpm } WpM, effektiv { $effective } WpM
+error-dir-not-found = Verzeichnis nicht gefunden: { $dir }
+error-dir-unreadable = Kann Verzeichnis { $dir } nicht lesen: { $error }
+error-no-audio-files = Keine Audio-Dateien in { $dir } gefunden!
+error-unreadable = Kann { $path } nicht lesen: { $error }
+error-speech = Sprachausgabe nicht möglich: { $error }
+error-no-audio-output = Keine Audio-Ausgabe gefunden, die Zeichen laufen stumm in ihrer normalen Länge
+error-nothing-to-send = In { $path } ist kein Zeichen aus der Zeichenauswahl
+error-unwritable = Kann { $path } nicht schreiben: { $error }
+error-config-parse = Ungültige Einstellungsdatei { $path }: { $error }
+error-config-invalid = Ungültiger Wert für { $key }: { $value } (erlaubt: { $min } bis { $max })
+error-profile-name = Ungültiger Profilname: { $name } (keine Pfadtrenner und kein „..“)
+error-profile-corrupt = Profil { $path } ist beschädigt ({ $error }), gesichert als { $backup }
+error-profile-unsaved = Kann Profil { $path } nicht speichern: { $error }
+error-profile-corrupt-kept = Profil { $path } ist beschädigt ({ $error }) und wird nicht überschrieben
+error-config-invalid-key = Ungültige Taste für { $key }: { $value } (unbekannt oder schon belegt)
+
+## Ausgeschriebene Zeichen für Texte
+
+spell-0 = null
+spell-1 = eins
+spell-2 = zwei
+spell-3 = drei
+spell-4 = vier
+spell-5 = fünf
+spell-6 = sechs
+spell-7 = sieben
+spell-8 = acht
+spell-9 = neun
+spell-period = punkt
+spell-comma = komma
+spell-question = fragezeichen
+spell-exclamation = ausrufezeichen
+spell-equals = gleich
+spell-slash = strich
+spell-hyphen = minus
+spell-colon = doppelpunkt
+spell-semicolon = semikolon
+spell-plus = plus
+spell-ampersand = und
+spell-at = at
+spell-percent = prozent
+spell-parenthesis = klammer
+spell-quote = zitat
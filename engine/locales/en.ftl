@@ -0,0 +1,236 @@
+## Session
+
+prompt-ready = Ready...
+prompt-listening = Listen...
+prompt-awaiting-answer = Which character?
+prompt-wrong =
+    Expected: { $expected }
+    Your answer: { $typed }
+
+    Repeating { $repeats }x...
+prompt-skipped = Skipped: { $expected }
+prompt-repeat-solution =
+    Solution: { $name }
+
+    Repetition { $index }/{ $total }
+prompt-repeat-typed =
+    Your answer: { $name }
+
+    Repetition { $index }/{ $total }
+prompt-pause = Pause...
+prompt-stopped = Stopped
+prompt-nothing-selected = No characters selected
+prompt-time-up = Time is up
+prompt-items-done = All characters sent
+prompt-target-reached = Target accuracy reached
+prompt-playback-failed =
+    Cannot play { $name }:
+    { $error }
+prompt-copy-check = What was sent?
+prompt-text-finished = End of text
+prompt-streaming =
+    Copying: { $sent } characters sent
+
+    { $typed }
+prompt-stream-result =
+    Sent:  { $sent }
+    Typed: { $typed }
+
+    Correct: { $correct } of { $total } (errors: { $errors })
+pause-overlay =
+    Paused
+
+    Continue with { $key }
+score = Correct: { $correct } | Wrong: { $wrong }
+
+## Character selection
+
+preset-all = All
+preset-letters = Letters
+preset-digits = Digits
+preset-punctuation = Punctuation
+preset-prosigns = Prosigns
+preset-hard-letters = YQZJX
+preset-custom = Custom
+drill-random = Random
+drill-confusables = Confusables
+drill-copy-behind = Copy behind
+char-select-count = Character selection: { $selected } of { $total } selected
+drill-random-info = Random characters from the selection
+drill-copy-behind-info = Characters keep coming without waiting for answers, just copy along behind them
+drill-groups = Groups: { $groups }
+
+## Buttons
+
+button-start = Start
+button-pause = Pause
+button-resume = Resume
+button-stop = Stop
+button-characters = Characters
+button-submit = Submit
+button-replay = Again
+button-heatmap = Confusions
+button-stats = Statistics
+button-settings = Settings
+button-back = Back
+button-done = Done
+button-export = Export
+button-save = Save
+button-continue = Continue
+button-profile = Profile
+button-create = Create
+button-keys = Keys
+
+## Confusions and statistics
+
+heatmap-title = Confusions ({ $profile }): row = sent, column = typed
+heatmap-empty = No mistakes recorded yet.
+heatmap-top = Most frequent: { $confusions }
+stats-summary = Statistics ({ $profile }): { $answers } answers, { $accuracy }% correct, { $replays } replays, { $minutes } min | Streak: { $streak } days (best { $best })
+stats-daily-accuracy = Accuracy per day (%)
+stats-daily-minutes = Minutes per day
+stats-per-character = Accuracy and median reaction time per character
+stats-no-data = No data yet
+export-saved =
+    Saved:
+    { $csv }
+    { $json }
+export-failed = Export failed: { $error }
+
+## Practice sessions
+
+home-title = New session
+home-profile = Profile: { $profile }
+profile-title = Choose a profile
+profile-new = New profile:
+home-limits = Session ends at: { $limits }
+home-unlimited = The session runs until it is stopped
+limit-minutes = { $minutes } min
+limit-items = { $items } characters
+limit-target = { $percent } % accuracy
+limit-or = { " " }or{ " " }
+summary-title = Session finished
+summary-duration = Duration: { $minutes } min
+summary-no-answers = No answers
+summary-accuracy = Accuracy: { $accuracy } % ({ $correct } of { $total })
+summary-latency = Reaction time (median): { $ms } ms
+summary-replays = Played again: { $replays } times
+summary-weakest = Weakest characters: { $characters }
+summary-previous = Previous session: { $accuracy } % ({ $delta } percentage points)
+summary-previous-latency = Reaction time previous session: { $ms } ms ({ $delta } ms)
+summary-first = First session, nothing to compare yet
+
+## Settings
+
+settings-title = Settings
+setting-pause = Pause after a round
+setting-repeat-pause = Pause between repetitions
+setting-repeats = Repetitions after a mistake
+setting-lag = Copy behind: allowed lag
+setting-gap = Copy behind: gap between characters
+setting-words-per-chunk = Text: words per chunk
+setting-unknown = Text: unknown characters
+setting-copy-check = Text: check what was copied
+setting-session-minutes = Session: duration
+setting-session-items = Session: number of characters
+setting-target-accuracy = Session: target accuracy
+setting-replay-penalty = Session: penalty after replay
+policy-skip = skip
+policy-substitute = substitute
+policy-spell = spell out
+on = on
+off = off
+setting-language = Language (after restart)
+setting-large-font = Large font
+setting-fullscreen = Full screen (F11)
+setting-compact = Compact view
+setting-accessible = Audio feedback
+setting-answer-back = Then the answer
+answer-back-off = off
+answer-back-morse = in Morse
+answer-back-speech = spoken
+a11y-correct = Correct: { $answer }
+a11y-wrong = Wrong, it was { $answer }
+language-system = System
+settings-saved = Saved: { $path }
+settings-not-saved = Not saved: { $error }
+key-bindings-title = Key bindings
+key-start-stop = Start / stop session
+key-pause = Pause / resume
+key-replay = Play character again
+key-skip = Skip character
+key-press = Press a key...
+key-bindings-hint = F1 to F10, F12, arrow keys and Insert to Page Down can be used. Escape cancels.
+key-not-allowed = { $key } cannot be used
+hotkeys-hint = { $start_stop } Stop | { $pause } Pause | { $replay } Again | { $skip } Skip
+
+## Terminal
+
+tui-status = Status
+tui-status-paused = Status (paused)
+tui-answer = Answer
+tui-home = { $key } starts a new session.
+tui-help = { $start_stop } Start/Stop | { $pause } Pause/Resume | { $replay } Again | { $skip } Skip | Tab Copy behind on/off | Enter Submit | Esc Quit
+tui-usage = Usage: cw-listen-tui [--audio rodio|loopback|silent]
+tui-missing-value = --audio needs a value
+tui-unknown-argument = Unknown argument: { $argument }
+tui-unknown-backend = Unknown audio output: { $backend }
+tui-no-audio = No audio output available ({ $error }), try starting with --audio loopback
+
+## Command line and errors
+
+error = Error: { $error }
+warning-no-audio = Warning: No audio output ({ $error }), characters play silently at their normal length
+warning-lesson-missing = Warning: Not available and skipped: { $characters }
+audio-error = Audio error: { $error }
+cli-unknown-characters = Unknown characters: { $characters }
+cli-invalid-duration = --duration must be greater than 0, not { $value }
+cli-invalid-wpm = --wpm must be between 5 and 60, not { $value }
+cli-invalid-farnsworth = --farnsworth must be between 0 and --wpm ({ $wpm }), not { $value }
+cli-invalid-record-format = --record needs a file ending in .wav, .flac or .ogg, not { $path }
+record-saved = Recording saved: { $audio }, answer key: { $key }
+record-speed = Speed: { $wpm } wpm
+record-speed-farnsworth = Speed: { $wpm } wpm, effective { $effective } wpm
+error-dir-not-found = Directory not found: { $dir }
+error-dir-unreadable = Cannot read directory { $dir }: { $error }
+error-no-audio-files = No audio files found in { $dir }!
+error-unreadable = Cannot read { $path }: { $error }
+error-speech = Speech output failed: { $error }
+error-no-audio-output = No audio output found, characters play silently at their normal length
+error-nothing-to-send = { $path } contains no characters from the selection
+error-unwritable = Cannot write { $path }: { $error }
+error-config-parse = Invalid settings file { $path }: { $error }
+error-config-invalid = Invalid value for { $key }: { $value } (allowed: { $min } to { $max })
+error-profile-name = Invalid profile name: { $name } (no path separators and no "..")
+error-profile-corrupt = Profile { $path } is corrupt ({ $error }), kept as { $backup }
+error-profile-unsaved = Cannot save profile { $path }: { $error }
+error-profile-corrupt-kept = Profile { $path } is corrupt ({ $error }) and will not be overwritten
+error-config-invalid-key = Invalid key for { $key }: { $value } (unknown or already in use)
+
+## Spelled-out characters for texts
+
+spell-0 = zero
+spell-1 = one
+spell-2 = two
+spell-3 = three
+spell-4 = four
+spell-5 = five
+spell-6 = six
+spell-7 = seven
+spell-8 = eight
+spell-9 = nine
+spell-period = stop
+spell-comma = comma
+spell-question = query
+spell-exclamation = exclamation
+spell-equals = equals
+spell-slash = slash
+spell-hyphen = dash
+spell-colon = colon
+spell-semicolon = semicolon
+spell-plus = plus
+spell-ampersand = and
+spell-at = at
+spell-percent = percent
+spell-parenthesis = bracket
+spell-quote = quote
@@ -0,0 +1,90 @@
+use std::{io, process::{Child, Command, Stdio}, time::Duration};
+use serde::{Deserialize, Serialize};
+
+use crate::morse::{append_silence, append_tone};
+use crate::tr;
+
+/// Was nach einer Antwort zusaetzlich zur Anzeige ausgegeben wird.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum AnswerBack {
+    /// Nur der Rueckmeldeton
+    #[default]
+    Off,
+    /// Das richtige Zeichen noch einmal in Morse
+    Morse,
+    /// Das richtige Zeichen ueber die Sprachausgabe
+    Speech,
+}
+
+impl AnswerBack {
+    pub const ALL: [AnswerBack; 3] = [AnswerBack::Off, AnswerBack::Morse, AnswerBack::Speech];
+
+    pub fn label(self) -> String {
+        tr!(match self {
+            AnswerBack::Off => "answer-back-off",
+            AnswerBack::Morse => "answer-back-morse",
+            AnswerBack::Speech => "answer-back-speech",
+        })
+    }
+}
+
+/// Kurzer Ton nach einer Antwort: aufsteigend bei richtig, zweimal tief bei falsch.
+pub fn cue(correct: bool) -> Vec<f32> {
+    let mut samples = Vec::new();
+
+    if correct {
+        append_tone(&mut samples, Duration::from_millis(70), 880.0);
+        append_tone(&mut samples, Duration::from_millis(110), 1320.0);
+    } else {
+        append_tone(&mut samples, Duration::from_millis(150), 220.0);
+        append_silence(&mut samples, Duration::from_millis(60));
+        append_tone(&mut samples, Duration::from_millis(150), 220.0);
+    }
+
+    // Etwas Luft, bevor eine Antwort in Morse folgt
+    append_silence(&mut samples, Duration::from_millis(200));
+    samples
+}
+
+/// Sprachausgabe ueber ein externes Programm wie `spd-say` oder `espeak-ng`,
+/// das den Text als letztes Argument bekommt.
+pub struct Speech {
+    program: String,
+    args: Vec<String>,
+    running: Option<Child>,
+}
+
+impl Speech {
+    /// `None`, wenn der Befehl leer ist.
+    pub fn new(command: &str) -> Option<Self> {
+        let mut parts = command.split_whitespace().map(str::to_string);
+        let program = parts.next()?;
+
+        Some(Self {
+            program,
+            args: parts.collect(),
+            running: None,
+        })
+    }
+
+    /// Spricht `text` und bricht dabei eine noch laufende Ansage ab.
+    pub fn say(&mut self, text: &str) -> io::Result<()> {
+        if let Some(mut child) = self.running.take()
+            && child.try_wait()?.is_none()
+        {
+            child.kill()?;
+            child.wait()?;
+        }
+
+        let child = Command::new(&self.program)
+            .args(&self.args)
+            .arg(text)
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        self.running = Some(child);
+        Ok(())
+    }
+}
@@ -0,0 +1,187 @@
+/// Ein Schritt beim Abgleich von gesendetem und getipptem Text; die Zahlen sind Positionen darin.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AlignOp {
+    Match { sent: usize, typed: usize },
+    Substitute { sent: usize, typed: usize },
+    /// Gesendet, aber nicht getippt
+    Missed { sent: usize },
+    /// Getippt, aber nicht gesendet
+    Extra { typed: usize },
+}
+
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct Alignment {
+    pub ops: Vec<AlignOp>,
+    /// Levenshtein-Distanz, also Anzahl aller Fehler
+    pub distance: usize,
+}
+
+impl Alignment {
+    pub fn correct(&self) -> usize {
+        self.ops.iter().filter(|op| matches!(op, AlignOp::Match { .. })).count()
+    }
+}
+
+/// Ordnet den getippten Text dem gesendeten mit minimaler Editierdistanz zu.
+/// Zeit und Speicher wachsen mit `sent.len() * typed.len()`; fuer lange Texte `align_windowed`.
+pub fn align<T: PartialEq>(sent: &[T], typed: &[T]) -> Alignment {
+    let columns = typed.len() + 1;
+    let mut costs = vec![0usize; (sent.len() + 1) * columns];
+
+    for i in 0..=sent.len() {
+        for j in 0..=typed.len() {
+            costs[i * columns + j] = match (i, j) {
+                (0, _) => j,
+                (_, 0) => i,
+                _ => {
+                    let replace = costs[(i - 1) * columns + j - 1] + usize::from(sent[i - 1] != typed[j - 1]);
+                    let missed = costs[(i - 1) * columns + j] + 1;
+                    let extra = costs[i * columns + j - 1] + 1;
+                    replace.min(missed).min(extra)
+                }
+            };
+        }
+    }
+
+    // Rueckwaerts den guenstigsten Weg ablaufen, bei Gleichstand lieber Zeichen gegen Zeichen
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (sent.len(), typed.len());
+
+    while i > 0 || j > 0 {
+        let cost = costs[i * columns + j];
+
+        if i > 0 && j > 0 {
+            let same = sent[i - 1] == typed[j - 1];
+            if costs[(i - 1) * columns + j - 1] + usize::from(!same) == cost {
+                ops.push(if same {
+                    AlignOp::Match { sent: i - 1, typed: j - 1 }
+                } else {
+                    AlignOp::Substitute { sent: i - 1, typed: j - 1 }
+                });
+                i -= 1;
+                j -= 1;
+                continue;
+            }
+        }
+
+        if i > 0 && costs[(i - 1) * columns + j] + 1 == cost {
+            ops.push(AlignOp::Missed { sent: i - 1 });
+            i -= 1;
+        } else {
+            ops.push(AlignOp::Extra { typed: j - 1 });
+            j -= 1;
+        }
+    }
+
+    ops.reverse();
+    Alignment {
+        ops,
+        distance: costs[sent.len() * columns + typed.len()],
+    }
+}
+
+/// Wie `align`, aber stueckweise ueber je `window` gesendete Zeichen, damit der Aufwand bei langen
+/// Sitzungen nur linear waechst. Von jedem Stueck zaehlt nur die erste Haelfte, der Rest wird mit
+/// dem naechsten Stueck neu abgeglichen. Liegt die Eingabe mehr als ein halbes Fenster daneben,
+/// kann das Ergebnis etwas schlechter als das optimale sein.
+pub fn align_windowed<T: PartialEq>(sent: &[T], typed: &[T], window: usize) -> Alignment {
+    let window = window.max(2);
+    let mut result = Alignment::default();
+    let (mut s, mut t) = (0, 0);
+
+    loop {
+        let s_end = (s + window).min(sent.len());
+        // Im letzten Stueck muessen alle uebrigen Eingaben unterkommen
+        let t_end = if s_end == sent.len() { typed.len() } else { (t + window).min(typed.len()) };
+        let part = align(&sent[s..s_end], &typed[t..t_end]);
+
+        if s_end == sent.len() && t_end == typed.len() {
+            result.distance += part.distance;
+            result.ops.extend(part.ops.into_iter().map(|op| shift(op, s, t)));
+            return result;
+        }
+
+        let (mut used_sent, mut used_typed) = (0, 0);
+        for op in part.ops {
+            let (sent_used, typed_used, error) = match op {
+                AlignOp::Match { sent, .. } | AlignOp::Substitute { sent, .. } | AlignOp::Missed { sent }
+                    if sent >= window / 2 =>
+                {
+                    break;
+                }
+                AlignOp::Match { .. } => (1, 1, 0),
+                AlignOp::Substitute { .. } => (1, 1, 1),
+                AlignOp::Missed { .. } => (1, 0, 1),
+                AlignOp::Extra { .. } => (0, 1, 1),
+            };
+
+            used_sent += sent_used;
+            used_typed += typed_used;
+            result.distance += error;
+            result.ops.push(shift(op, s, t));
+        }
+
+        s += used_sent;
+        t += used_typed;
+    }
+}
+
+fn shift(op: AlignOp, s: usize, t: usize) -> AlignOp {
+    match op {
+        AlignOp::Match { sent, typed } => AlignOp::Match { sent: sent + s, typed: typed + t },
+        AlignOp::Substitute { sent, typed } => AlignOp::Substitute { sent: sent + s, typed: typed + t },
+        AlignOp::Missed { sent } => AlignOp::Missed { sent: sent + s },
+        AlignOp::Extra { typed } => AlignOp::Extra { typed: typed + t },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn chars(text: &str) -> Vec<char> {
+        text.chars().collect()
+    }
+
+    #[test]
+    fn alignments() {
+        use AlignOp::*;
+
+        let cases: [(&str, &str, usize, &[AlignOp]); 7] = [
+            ("abc", "abc", 0, &[Match { sent: 0, typed: 0 }, Match { sent: 1, typed: 1 }, Match { sent: 2, typed: 2 }]),
+            ("ac", "abc", 1, &[Match { sent: 0, typed: 0 }, Extra { typed: 1 }, Match { sent: 1, typed: 2 }]),
+            ("abc", "ac", 1, &[Match { sent: 0, typed: 0 }, Missed { sent: 1 }, Match { sent: 2, typed: 1 }]),
+            ("abc", "axc", 1, &[Match { sent: 0, typed: 0 }, Substitute { sent: 1, typed: 1 }, Match { sent: 2, typed: 2 }]),
+            ("", "", 0, &[]),
+            ("ab", "", 2, &[Missed { sent: 0 }, Missed { sent: 1 }]),
+            ("", "ab", 2, &[Extra { typed: 0 }, Extra { typed: 1 }]),
+        ];
+
+        for (sent, typed, distance, ops) in cases {
+            let alignment = align(&chars(sent), &chars(typed));
+            assert_eq!(alignment.distance, distance, "{} / {}", sent, typed);
+            assert_eq!(alignment.ops, ops, "{} / {}", sent, typed);
+        }
+    }
+
+    #[test]
+    fn windowed_matches_full_alignment_for_short_texts() {
+        for (sent, typed) in [("abc", "abc"), ("ac", "abc"), ("paris", "pxris"), ("", "ab"), ("ab", "")] {
+            assert_eq!(align_windowed(&chars(sent), &chars(typed), 8), align(&chars(sent), &chars(typed)));
+        }
+    }
+
+    #[test]
+    fn windowed_covers_long_texts() {
+        let sent: Vec<char> = "kmuresnaptlwi".chars().cycle().take(1000).collect();
+        let mut typed = sent.clone();
+        typed.remove(500);
+        typed.insert(200, 'x');
+        typed[800] = '?';
+
+        let alignment = align_windowed(&sent, &typed, 64);
+        assert_eq!(alignment.distance, 3);
+        assert_eq!(alignment.correct(), 998);
+        assert_eq!(alignment.ops.iter().filter(|op| !matches!(op, AlignOp::Extra { .. })).count(), sent.len());
+    }
+}
@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use crate::library::Sound;
+use crate::session::{Effect, SessionEvent};
+
+/// Was eine Audio-Ausgabe ueber ihre Wiedergabe weiss; die Sitzung raet nicht selbst.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AudioEvent {
+    /// Der Sound ist geladen und laeuft
+    Started,
+    /// Der Sound ist vollstaendig gespielt
+    Finished,
+}
+
+impl From<AudioEvent> for SessionEvent {
+    fn from(event: AudioEvent) -> Self {
+        match event {
+            AudioEvent::Started => SessionEvent::PlaybackStarted,
+            AudioEvent::Finished => SessionEvent::PlaybackFinished,
+        }
+    }
+}
+
+/// Eine Audio-Ausgabe, ueber die ein Frontend die Effekte der Sitzung hoerbar macht.
+pub trait AudioBackend {
+    /// Spielt `sound` ab und ersetzt dabei eine laufende Wiedergabe.
+    fn play(&mut self, sound: &Sound);
+    fn stop(&mut self);
+    fn pause(&mut self);
+    fn resume(&mut self);
+    fn is_playing(&self) -> bool;
+
+    /// Laesst Zeit vergehen; Ausgaben ohne Geraet spielen danach weiter, alle bemerken hier das Ende.
+    fn advance(&mut self, _elapsed: Duration) {}
+
+    /// Beginn und Ende der Wiedergaben seit dem letzten Aufruf, in dieser Reihenfolge.
+    fn take_events(&mut self) -> Vec<AudioEvent>;
+
+    /// Letzter Fehler beim Oeffnen oder Dekodieren, zur Anzeige im Frontend.
+    fn last_error(&self) -> Option<&str> {
+        None
+    }
+
+    /// Setzt einen Effekt um, sofern er die Audio-Ausgabe betrifft.
+    fn apply(&mut self, effect: &Effect) {
+        match effect {
+            Effect::Play(sound) => self.play(sound),
+            Effect::StopAudio => self.stop(),
+            Effect::PauseAudio => self.pause(),
+            Effect::ResumeAudio => self.resume(),
+            Effect::Graded(_) => {}
+        }
+    }
+}
+
+/// Gibt nichts aus; jede Wiedergabe gilt sofort als beendet.
+#[derive(Default, Debug)]
+pub struct SilentBackend {
+    events: Vec<AudioEvent>,
+}
+
+impl AudioBackend for SilentBackend {
+    fn play(&mut self, _sound: &Sound) {
+        self.events.extend([AudioEvent::Started, AudioEvent::Finished]);
+    }
+
+    fn stop(&mut self) {}
+    fn pause(&mut self) {}
+    fn resume(&mut self) {}
+
+    fn is_playing(&self) -> bool {
+        false
+    }
+
+    fn take_events(&mut self) -> Vec<AudioEvent> {
+        std::mem::take(&mut self.events)
+    }
+}
@@ -0,0 +1,573 @@
+use std::{error::Error, fmt, fs, io, ops::RangeInclusive, path::{Path, PathBuf}, time::Duration};
+use serde::{Deserialize, Serialize};
+
+use crate::accessibility::AnswerBack;
+use crate::i18n::Language;
+use crate::session::{CopyBehind, Limits, Timings};
+use crate::text::{TextSettings, UnknownPolicy};
+use crate::tr;
+
+/// Erlaubte Werte; ausserhalb davon wird die Datei beim Start abgelehnt.
+pub const PAUSE_MS: RangeInclusive<u64> = 200..=30_000;
+pub const REPEAT_PAUSE_MS: RangeInclusive<u64> = 0..=10_000;
+pub const REPEATS: RangeInclusive<u32> = 0..=10;
+pub const LAG: RangeInclusive<u64> = 1..=3;
+pub const GAP_MS: RangeInclusive<u64> = 0..=5_000;
+pub const WORDS_PER_CHUNK: RangeInclusive<u64> = 1..=20;
+pub const SESSION_MINUTES: RangeInclusive<u64> = 0..=240;
+pub const SESSION_ITEMS: RangeInclusive<u64> = 0..=1_000;
+pub const TARGET_ACCURACY: RangeInclusive<u64> = 0..=100;
+pub const REPLAY_PENALTY: RangeInclusive<u64> = 0..=100;
+
+/// Tasten, die sich belegen lassen; Buchstaben, Ziffern, Enter und Leertaste braucht die Eingabe,
+/// F11 schaltet das Vollbild um und Escape bricht das Belegen ab.
+/// Jedes Frontend ordnet sie mit einem vollstaendigen `match` seinen eigenen Tasten zu.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BindableKey {
+    F1,
+    F2,
+    F3,
+    F4,
+    F5,
+    F6,
+    F7,
+    F8,
+    F9,
+    F10,
+    F12,
+    Insert,
+    Delete,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl BindableKey {
+    pub const ALL: [BindableKey; 21] = [
+        BindableKey::F1,
+        BindableKey::F2,
+        BindableKey::F3,
+        BindableKey::F4,
+        BindableKey::F5,
+        BindableKey::F6,
+        BindableKey::F7,
+        BindableKey::F8,
+        BindableKey::F9,
+        BindableKey::F10,
+        BindableKey::F12,
+        BindableKey::Insert,
+        BindableKey::Delete,
+        BindableKey::Home,
+        BindableKey::End,
+        BindableKey::PageUp,
+        BindableKey::PageDown,
+        BindableKey::Up,
+        BindableKey::Down,
+        BindableKey::Left,
+        BindableKey::Right,
+    ];
+
+    /// Name in der Datei und in Hinweisen, z.B. "F6".
+    pub fn name(self) -> &'static str {
+        match self {
+            BindableKey::F1 => "F1",
+            BindableKey::F2 => "F2",
+            BindableKey::F3 => "F3",
+            BindableKey::F4 => "F4",
+            BindableKey::F5 => "F5",
+            BindableKey::F6 => "F6",
+            BindableKey::F7 => "F7",
+            BindableKey::F8 => "F8",
+            BindableKey::F9 => "F9",
+            BindableKey::F10 => "F10",
+            BindableKey::F12 => "F12",
+            BindableKey::Insert => "Insert",
+            BindableKey::Delete => "Delete",
+            BindableKey::Home => "Home",
+            BindableKey::End => "End",
+            BindableKey::PageUp => "PageUp",
+            BindableKey::PageDown => "PageDown",
+            BindableKey::Up => "Up",
+            BindableKey::Down => "Down",
+            BindableKey::Left => "Left",
+            BindableKey::Right => "Right",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|key| key.name() == name)
+    }
+}
+
+/// Inhalt der `config.toml`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub timings: TimingsConfig,
+    pub copy_behind: CopyBehindConfig,
+    pub text: TextConfig,
+    pub session: SessionConfig,
+    pub ui: UiConfig,
+    pub accessibility: AccessibilityConfig,
+    pub keys: KeysConfig,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct UiConfig {
+    /// Fehlt die Angabe, gilt die Sprache des Systems
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<Language>,
+    /// Alles im Fenster groesser darstellen
+    pub large_font: bool,
+    /// Randloses Vollbild statt Fenster
+    pub fullscreen: bool,
+    /// Kleines Fenster mit engerem Layout, z.B. neben einem Logprogramm
+    pub compact: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct AccessibilityConfig {
+    /// Ergebnis jeder Antwort mit einem Ton melden
+    pub enabled: bool,
+    /// Richtiges Zeichen danach noch einmal ausgeben: "off", "morse" oder "speech"
+    pub answer_back: AnswerBack,
+    /// Programm fuer die Sprachausgabe, bekommt den Text als letztes Argument
+    pub speech_command: String,
+}
+
+impl Default for AccessibilityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            answer_back: AnswerBack::Off,
+            speech_command: "spd-say".to_string(),
+        }
+    }
+}
+
+/// Tastenbelegung im Fenster, jeweils der Name einer `BindableKey`.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeysConfig {
+    /// Sitzung starten bzw. beenden
+    pub start_stop: String,
+    pub pause: String,
+    /// Aktuelles Zeichen noch einmal abspielen
+    pub replay: String,
+    /// Aktuelles Zeichen ohne Bewertung ueberspringen
+    pub skip: String,
+}
+
+impl Default for KeysConfig {
+    fn default() -> Self {
+        Self {
+            start_stop: "F5".to_string(),
+            pause: "F6".to_string(),
+            replay: "F7".to_string(),
+            skip: "F8".to_string(),
+        }
+    }
+}
+
+impl KeysConfig {
+    pub fn get(&self, action: KeyAction) -> &str {
+        match action {
+            KeyAction::StartStop => &self.start_stop,
+            KeyAction::Pause => &self.pause,
+            KeyAction::Replay => &self.replay,
+            KeyAction::Skip => &self.skip,
+        }
+    }
+
+    /// Belegt `action` mit `key`; hatte eine andere Aktion diese Taste, bekommt sie die bisherige.
+    pub fn assign(&mut self, action: KeyAction, key: BindableKey) {
+        let previous = self.get(action).to_string();
+
+        for other in KeyAction::ALL {
+            if other != action && self.get(other) == key.name() {
+                *self.slot(other) = previous.clone();
+            }
+        }
+        *self.slot(action) = key.name().to_string();
+    }
+
+    /// Die belegte Taste; `None` nur bei einer Belegung, die `Config::load` abgelehnt haette.
+    pub fn key(&self, action: KeyAction) -> Option<BindableKey> {
+        BindableKey::from_name(self.get(action))
+    }
+
+    fn slot(&mut self, action: KeyAction) -> &mut String {
+        match action {
+            KeyAction::StartStop => &mut self.start_stop,
+            KeyAction::Pause => &mut self.pause,
+            KeyAction::Replay => &mut self.replay,
+            KeyAction::Skip => &mut self.skip,
+        }
+    }
+}
+
+/// Was sich im Fenster per Taste ausloesen laesst.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum KeyAction {
+    StartStop,
+    Pause,
+    Replay,
+    Skip,
+}
+
+impl KeyAction {
+    pub const ALL: [KeyAction; 4] = [KeyAction::StartStop, KeyAction::Pause, KeyAction::Replay, KeyAction::Skip];
+
+    /// Name in der Datei, fuer Fehlermeldungen.
+    pub fn key(self) -> &'static str {
+        match self {
+            KeyAction::StartStop => "keys.start_stop",
+            KeyAction::Pause => "keys.pause",
+            KeyAction::Replay => "keys.replay",
+            KeyAction::Skip => "keys.skip",
+        }
+    }
+
+    pub fn label(self) -> String {
+        tr!(match self {
+            KeyAction::StartStop => "key-start-stop",
+            KeyAction::Pause => "key-pause",
+            KeyAction::Replay => "key-replay",
+            KeyAction::Skip => "key-skip",
+        })
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct TimingsConfig {
+    /// Pause nach einer Runde, bevor das naechste Zeichen kommt
+    pub pause_ms: u64,
+    /// Pause zwischen den Wiederholungen nach einer falschen Antwort
+    pub repeat_pause_ms: u64,
+    /// Anzahl Wiederholungen nach einer falschen Antwort
+    pub repeats: u32,
+    /// Aus aelteren Dateien; Beginn und Ende melden inzwischen die Audio-Ausgaben selbst
+    #[serde(rename = "play_start_delay_ms", skip_serializing)]
+    ignored_play_start_delay_ms: Option<u64>,
+}
+
+impl Default for TimingsConfig {
+    fn default() -> Self {
+        Self::from(&Timings::default())
+    }
+}
+
+impl From<&Timings> for TimingsConfig {
+    fn from(timings: &Timings) -> Self {
+        Self {
+            pause_ms: timings.pause.as_millis() as u64,
+            repeat_pause_ms: timings.repeat_pause.as_millis() as u64,
+            repeats: timings.repeats,
+            ignored_play_start_delay_ms: None,
+        }
+    }
+}
+
+impl TimingsConfig {
+    pub fn timings(&self) -> Timings {
+        Timings {
+            pause: Duration::from_millis(self.pause_ms),
+            repeat_pause: Duration::from_millis(self.repeat_pause_ms),
+            repeats: self.repeats,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct CopyBehindConfig {
+    /// So viele Zeichen darf man beim Mitschreiben hinterherhaengen
+    pub lag: u64,
+    /// Pause zwischen zwei Zeichen beim Mitschreiben
+    pub gap_ms: u64,
+}
+
+impl Default for CopyBehindConfig {
+    fn default() -> Self {
+        let copy_behind = CopyBehind::default();
+        Self {
+            lag: copy_behind.lag as u64,
+            gap_ms: copy_behind.gap.as_millis() as u64,
+        }
+    }
+}
+
+impl CopyBehindConfig {
+    pub fn copy_behind(&self) -> CopyBehind {
+        CopyBehind {
+            lag: self.lag as usize,
+            gap: Duration::from_millis(self.gap_ms),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct TextConfig {
+    /// So viele Woerter werden am Stueck gesendet
+    pub words_per_chunk: u64,
+    /// Umgang mit Zeichen ausserhalb der Zeichenauswahl: "skip", "substitute" oder "spell"
+    pub unknown: UnknownPolicy,
+    /// Nach jedem Stueck das Mitgeschriebene abfragen
+    pub copy_check: bool,
+}
+
+impl Default for TextConfig {
+    fn default() -> Self {
+        let settings = TextSettings::default();
+        Self {
+            words_per_chunk: settings.words_per_chunk as u64,
+            unknown: settings.policy,
+            copy_check: settings.copy_check,
+        }
+    }
+}
+
+impl TextConfig {
+    pub fn settings(&self) -> TextSettings {
+        TextSettings {
+            words_per_chunk: self.words_per_chunk as usize,
+            policy: self.unknown,
+            copy_check: self.copy_check,
+        }
+    }
+}
+
+/// Wann eine Sitzung von selbst endet; 0 heisst jeweils ohne Grenze.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct SessionConfig {
+    /// Trainingszeit in Minuten
+    pub minutes: u64,
+    /// Anzahl gesendeter Zeichen bzw. Stuecke
+    pub items: u64,
+    /// Trefferquote in Prozent ueber die letzten Antworten
+    pub target_accuracy: u64,
+    /// Abzug in Prozent fuer eine richtige Antwort, wenn das Zeichen nochmal angehoert wurde
+    pub replay_penalty: u64,
+}
+
+impl SessionConfig {
+    pub fn limits(&self) -> Limits {
+        Limits {
+            duration: (self.minutes > 0).then(|| Duration::from_secs(self.minutes * 60)),
+            items: (self.items > 0).then_some(self.items as u32),
+            target_accuracy: (self.target_accuracy > 0).then(|| self.target_accuracy as f32 / 100.0),
+        }
+    }
+
+    pub fn replay_credit(&self) -> f32 {
+        1.0 - self.replay_penalty.min(100) as f32 / 100.0
+    }
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Unreadable(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Invalid { key: &'static str, value: u64, min: u64, max: u64 },
+    /// Unbekannte oder doppelt belegte Taste
+    InvalidKey { key: &'static str, value: String },
+    Unwritable(PathBuf, io::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Unreadable(path, err) => write!(
+                f,
+                "{}",
+                tr!("error-unreadable", path = path.display().to_string(), error = err.to_string())
+            ),
+            ConfigError::Parse(path, err) => write!(
+                f,
+                "{}",
+                tr!("error-config-parse", path = path.display().to_string(), error = err.to_string())
+            ),
+            ConfigError::Invalid { key, value, min, max } => write!(
+                f,
+                "{}",
+                tr!("error-config-invalid", key = *key, value = value.to_string(), min = min.to_string(), max = max.to_string())
+            ),
+            ConfigError::InvalidKey { key, value } => write!(
+                f,
+                "{}",
+                tr!("error-config-invalid-key", key = *key, value = value.as_str())
+            ),
+            ConfigError::Unwritable(path, err) => write!(
+                f,
+                "{}",
+                tr!("error-unwritable", path = path.display().to_string(), error = err.to_string())
+            ),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+impl Config {
+    /// Standardort der Datei im Konfigurationsverzeichnis des Benutzers.
+    pub fn default_path() -> PathBuf {
+        dirs::config_dir()
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("cw-listen-trainer")
+            .join("config.toml")
+    }
+
+    /// Liest und prueft die Datei; fehlt sie, gelten die Standardwerte.
+    ///
+    /// Die Sprache aus `ui.language` steht erst danach fest. Meldungen zu einer fehlerhaften
+    /// Datei erscheinen deshalb in der Sprache des Systems (siehe `i18n::init`).
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(ConfigError::Unreadable(path.to_path_buf(), err)),
+        };
+
+        let config: Self = toml::from_str(&content).map_err(|err| ConfigError::Parse(path.to_path_buf(), err))?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        let timings = &self.timings;
+
+        check("timings.pause_ms", timings.pause_ms, &PAUSE_MS)?;
+        check("timings.repeat_pause_ms", timings.repeat_pause_ms, &REPEAT_PAUSE_MS)?;
+        check("timings.repeats", timings.repeats as u64, &(*REPEATS.start() as u64..=*REPEATS.end() as u64))?;
+        check("copy_behind.lag", self.copy_behind.lag, &LAG)?;
+        check("copy_behind.gap_ms", self.copy_behind.gap_ms, &GAP_MS)?;
+        check("text.words_per_chunk", self.text.words_per_chunk, &WORDS_PER_CHUNK)?;
+        check("session.minutes", self.session.minutes, &SESSION_MINUTES)?;
+        check("session.items", self.session.items, &SESSION_ITEMS)?;
+        check("session.target_accuracy", self.session.target_accuracy, &TARGET_ACCURACY)?;
+        check("session.replay_penalty", self.session.replay_penalty, &REPLAY_PENALTY)?;
+        check_keys(&self.keys)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), ConfigError> {
+        self.validate()?;
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| ConfigError::Unwritable(dir.to_path_buf(), err))?;
+        }
+
+        let content = toml::to_string_pretty(self).unwrap_or_default();
+        fs::write(path, content).map_err(|err| ConfigError::Unwritable(path.to_path_buf(), err))
+    }
+}
+
+fn check(key: &'static str, value: u64, allowed: &RangeInclusive<u64>) -> Result<(), ConfigError> {
+    if allowed.contains(&value) {
+        return Ok(());
+    }
+
+    Err(ConfigError::Invalid {
+        key,
+        value,
+        min: *allowed.start(),
+        max: *allowed.end(),
+    })
+}
+
+/// Jede Taste muss belegbar sein und darf nur eine Aktion ausloesen.
+fn check_keys(keys: &KeysConfig) -> Result<(), ConfigError> {
+    for (index, action) in KeyAction::ALL.into_iter().enumerate() {
+        let value = keys.get(action);
+        let duplicate = KeyAction::ALL[..index].iter().any(|other| keys.get(*other) == value);
+
+        if BindableKey::from_name(value).is_none() || duplicate {
+            return Err(ConfigError::InvalidKey { key: action.key(), value: value.to_string() });
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_are_valid() {
+        assert!(Config::default().validate().is_ok());
+    }
+
+    #[test]
+    fn out_of_range_values_are_rejected() {
+        let mut config = Config::default();
+        config.timings.pause_ms = *PAUSE_MS.end() + 1;
+
+        assert!(matches!(
+            config.validate(),
+            Err(ConfigError::Invalid { key: "timings.pause_ms", min: 200, max: 30_000, .. })
+        ));
+    }
+
+    #[test]
+    fn unknown_key_names_are_rejected() {
+        let mut config = Config::default();
+        config.keys.pause = "F11".to_string();
+
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidKey { key: "keys.pause", .. })));
+    }
+
+    #[test]
+    fn keys_may_only_be_bound_once() {
+        let mut config = Config::default();
+        config.keys.skip = config.keys.replay.clone();
+
+        assert!(matches!(config.validate(), Err(ConfigError::InvalidKey { key: "keys.skip", .. })));
+    }
+
+    #[test]
+    fn assign_swaps_with_the_previous_owner() {
+        let mut keys = KeysConfig::default();
+        let replay = keys.replay.clone();
+        let pause = keys.pause.clone();
+        keys.assign(KeyAction::Pause, keys.key(KeyAction::Replay).unwrap());
+
+        assert_eq!(keys.pause, replay);
+        assert_eq!(keys.replay, pause);
+        assert!(check_keys(&keys).is_ok());
+    }
+
+    #[test]
+    fn key_names_round_trip() {
+        for key in BindableKey::ALL {
+            assert_eq!(BindableKey::from_name(key.name()), Some(key));
+        }
+        assert_eq!(BindableKey::from_name("F11"), None);
+    }
+
+    #[test]
+    fn old_play_start_delay_is_ignored() {
+        let config: Config = toml::from_str("[timings]\npause_ms = 3000\nplay_start_delay_ms = 500\n").unwrap();
+        assert_eq!(config.timings.timings().pause, Duration::from_secs(3));
+        assert!(!toml::to_string(&config).unwrap().contains("play_start_delay_ms"));
+    }
+
+    #[test]
+    fn invalid_file_is_reported() {
+        let path = std::env::temp_dir().join(format!("cw-engine-config-{}.toml", std::process::id()));
+        fs::write(&path, "[session]\nreplay_penalty = 101\n").unwrap();
+        let result = Config::load(&path);
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(result, Err(ConfigError::Invalid { key: "session.replay_penalty", .. })));
+    }
+}
@@ -0,0 +1,147 @@
+use std::{cmp::Reverse, collections::{BTreeMap, BTreeSet}};
+use serde::{Deserialize, Serialize};
+
+/// Zaehlt pro gesendetem Zeichen, was getippt wurde (Diagonale = richtig).
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct ConfusionMatrix {
+    counts: BTreeMap<String, BTreeMap<String, u32>>,
+}
+
+impl ConfusionMatrix {
+    pub fn record(&mut self, expected: &str, typed: &str) {
+        *self
+            .counts
+            .entry(expected.to_lowercase())
+            .or_default()
+            .entry(typed.trim().to_lowercase())
+            .or_default() += 1;
+    }
+
+    pub fn count(&self, expected: &str, typed: &str) -> u32 {
+        self.counts
+            .get(expected)
+            .and_then(|row| row.get(typed))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    pub fn total(&self, expected: &str) -> u32 {
+        self.counts.get(expected).map_or(0, |row| row.values().sum())
+    }
+
+    pub fn errors(&self, expected: &str) -> u32 {
+        self.total(expected) - self.count(expected, expected)
+    }
+
+    /// Alle Verwechslungen (gesendet, getippt, Anzahl), die haeufigsten zuerst.
+    pub fn confusions(&self) -> Vec<(&str, &str, u32)> {
+        let mut confusions: Vec<_> = self
+            .counts
+            .iter()
+            .flat_map(|(expected, row)| {
+                row.iter()
+                    .filter(move |(typed, _)| *typed != expected)
+                    .map(move |(typed, count)| (expected.as_str(), typed.as_str(), *count))
+            })
+            .collect();
+
+        confusions.sort_by_key(|confusion| Reverse(confusion.2));
+        confusions
+    }
+
+    /// Wie oft `a` und `b` verwechselt wurden, egal in welche Richtung.
+    pub fn pair_count(&self, a: &str, b: &str) -> u32 {
+        if a == b {
+            return 0;
+        }
+        self.count(a, b) + self.count(b, a)
+    }
+
+    /// Verwechselte Paare ohne Richtung (alphabetisch sortiert), die haeufigsten zuerst.
+    pub fn pairs(&self) -> Vec<(&str, &str, u32)> {
+        let mut pairs: BTreeMap<(&str, &str), u32> = BTreeMap::new();
+        for (expected, typed, count) in self.confusions() {
+            *pairs.entry((expected.min(typed), expected.max(typed))).or_default() += count;
+        }
+
+        let mut pairs: Vec<_> = pairs.into_iter().map(|((a, b), count)| (a, b, count)).collect();
+        pairs.sort_by_key(|pair| Reverse(pair.2));
+        pairs
+    }
+
+    /// Die Zeichen mit den meisten Fehlern (hoechstens `max_rows`), alphabetisch.
+    pub fn rows(&self, max_rows: usize) -> Vec<&str> {
+        let mut rows: Vec<&str> = self
+            .counts
+            .keys()
+            .map(String::as_str)
+            .filter(|expected| self.errors(expected) > 0)
+            .collect();
+
+        rows.sort_by_key(|expected| Reverse(self.errors(expected)));
+        rows.truncate(max_rows);
+        rows.sort();
+        rows
+    }
+
+    /// Die Zeilenzeichen plus das, was dort am haeufigsten getippt wurde.
+    pub fn columns(&self, rows: &[&str], max_columns: usize) -> Vec<String> {
+        let mut columns: BTreeSet<String> = rows.iter().map(|row| row.to_string()).collect();
+
+        for (expected, typed, _) in self.confusions() {
+            if columns.len() >= max_columns {
+                break;
+            }
+            if rows.contains(&expected) {
+                columns.insert(typed.to_string());
+            }
+        }
+
+        columns.into_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matrix(answers: &[(&str, &str)]) -> ConfusionMatrix {
+        let mut matrix = ConfusionMatrix::default();
+        for (expected, typed) in answers {
+            matrix.record(expected, typed);
+        }
+        matrix
+    }
+
+    #[test]
+    fn answers_are_counted_per_character() {
+        let matrix = matrix(&[("B", "6 "), ("b", "b"), ("b", "6"), ("s", "s")]);
+
+        assert_eq!(matrix.count("b", "6"), 2);
+        assert_eq!(matrix.count("b", "b"), 1);
+        assert_eq!(matrix.total("b"), 3);
+        assert_eq!(matrix.errors("b"), 2);
+        assert_eq!(matrix.errors("s"), 0);
+        assert_eq!(matrix.total("x"), 0);
+    }
+
+    #[test]
+    fn pairs_count_both_directions() {
+        let matrix = matrix(&[("h", "5"), ("5", "h"), ("5", "h"), ("h", "h")]);
+
+        assert_eq!(matrix.pair_count("h", "5"), 3);
+        assert_eq!(matrix.pair_count("5", "h"), 3);
+        assert_eq!(matrix.pair_count("h", "h"), 0);
+        assert_eq!(matrix.pair_count("h", "s"), 0);
+    }
+
+    #[test]
+    fn most_confused_come_first() {
+        let matrix = matrix(&[("k", "r"), ("v", "3"), ("v", "3"), ("3", "v"), ("b", "6"), ("b", "6")]);
+
+        assert_eq!(matrix.confusions(), [("b", "6", 2), ("v", "3", 2), ("3", "v", 1), ("k", "r", 1)]);
+        assert_eq!(matrix.pairs(), [("3", "v", 3), ("6", "b", 2), ("k", "r", 1)]);
+        assert_eq!(matrix.rows(2), ["b", "v"]);
+        assert_eq!(matrix.columns(&["b", "v"], 4), ["3", "6", "b", "v"]);
+    }
+}
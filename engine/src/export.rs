@@ -0,0 +1,116 @@
+use std::{fs, io, path::{Path, PathBuf}};
+use chrono::{Local, TimeZone};
+use serde::Serialize;
+
+use crate::profile::{data_dir, Profile};
+use crate::stats::AnswerRecord;
+
+/// Eine Zeile im Export, mit lesbarem Zeitstempel statt Unix-Zeit.
+#[derive(Serialize)]
+struct ExportRow<'a> {
+    timestamp: String,
+    expected: &'a str,
+    typed: &'a str,
+    correct: bool,
+    latency_ms: u64,
+    wpm: Option<f32>,
+    pitch_hz: Option<f32>,
+    noise_level: Option<f32>,
+    replays: u32,
+    score: f32,
+}
+
+impl<'a> From<&'a AnswerRecord> for ExportRow<'a> {
+    fn from(record: &'a AnswerRecord) -> Self {
+        let timestamp = Local
+            .timestamp_opt(record.timestamp, 0)
+            .single()
+            .map(|time| time.to_rfc3339())
+            .unwrap_or_default();
+
+        Self {
+            timestamp,
+            expected: &record.expected,
+            typed: &record.typed,
+            correct: record.correct,
+            latency_ms: record.latency_ms,
+            wpm: record.wpm,
+            pitch_hz: record.pitch_hz,
+            noise_level: record.noise_level,
+            replays: record.replays,
+            score: record.score(),
+        }
+    }
+}
+
+pub fn export_dir() -> PathBuf {
+    data_dir().join("exports")
+}
+
+/// Schreibt alle Antworten des Profils als CSV und JSON nach `dir`.
+pub fn export_history(profile: &Profile, dir: &Path) -> io::Result<(PathBuf, PathBuf)> {
+    fs::create_dir_all(dir)?;
+
+    let stem = format!("{}-{}", profile.name, Local::now().format("%Y%m%d-%H%M%S"));
+    let csv_path = dir.join(format!("{}.csv", stem));
+    let json_path = dir.join(format!("{}.json", stem));
+
+    let rows: Vec<ExportRow> = profile.data.history.iter().map(ExportRow::from).collect();
+
+    let mut writer = csv::Writer::from_path(&csv_path)?;
+    for row in &rows {
+        writer.serialize(row)?;
+    }
+    writer.flush()?;
+
+    let json = serde_json::to_string_pretty(&rows)?;
+    fs::write(&json_path, json)?;
+
+    Ok((csv_path, json_path))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::library::Signal;
+    use crate::morse::Speed;
+    use crate::session::Grade;
+
+    #[test]
+    fn signal_columns_are_filled() {
+        let speed = Speed {
+            wpm: 25.0,
+            farnsworth: None,
+        };
+        let mut profile = Profile::temporary("export");
+        profile.record(&Grade {
+            expected: "a".to_string(),
+            typed: "a".to_string(),
+            correct: true,
+            latency: Duration::from_millis(400),
+            replays: 0,
+            credit: 1.0,
+            signal: Signal::synthesized(speed, 700.0),
+        });
+
+        let dir = std::env::temp_dir().join(format!("cw-export-{}", std::process::id()));
+        let (csv_path, json_path) = export_history(&profile, &dir).unwrap();
+
+        let mut reader = csv::Reader::from_path(&csv_path).unwrap();
+        let headers = reader.headers().unwrap().clone();
+        let row = reader.records().next().unwrap().unwrap();
+        let column = |name: &str| headers.iter().position(|header| header == name).map(|index| row[index].to_string());
+        assert_eq!(column("wpm").as_deref(), Some("25.0"));
+        assert_eq!(column("pitch_hz").as_deref(), Some("700.0"));
+        assert_eq!(column("noise_level").as_deref(), Some("0.0"));
+
+        let json: serde_json::Value = serde_json::from_str(&fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(json[0]["wpm"], 25.0);
+        assert_eq!(json[0]["pitch_hz"], 700.0);
+        assert_eq!(json[0]["noise_level"], 0.0);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,372 @@
+//! Einfacher FLAC-Encoder fuer die erzeugten Mono-Aufnahmen, als `.flac` oder in einem Ogg-Container.
+
+use ogg::writing::{PacketWriteEndInfo, PacketWriter};
+
+use crate::morse::SAMPLE_RATE;
+
+// Kurze Bloecke, damit die Pausen zwischen den Zeichen als konstante Bloecke fast nichts kosten
+const BLOCK_SIZE: usize = 1152;
+const BITS_PER_SAMPLE: u32 = 16;
+const OGG_SERIAL: u32 = 0x4357_4c54;
+
+/// Kodiert 16-Bit-Samples als FLAC-Datei.
+pub fn encode(samples: &[i16]) -> Vec<u8> {
+    let mut bytes = b"fLaC".to_vec();
+    bytes.extend(stream_info(samples.len(), true));
+    for frame in frames(samples) {
+        bytes.extend(frame);
+    }
+    bytes
+}
+
+/// Kodiert 16-Bit-Samples als FLAC in Ogg nach der Zuordnung von xiph.org.
+pub fn encode_ogg(samples: &[i16]) -> Vec<u8> {
+    let mut writer = PacketWriter::new(Vec::new());
+
+    let mut first = vec![0x7f];
+    first.extend_from_slice(b"FLAC");
+    // Version 1.0 und ein weiterer Header-Block nach diesem
+    first.extend_from_slice(&[1, 0, 0, 1]);
+    first.extend_from_slice(b"fLaC");
+    first.extend(stream_info(samples.len(), false));
+
+    let frames = frames(samples);
+    let mut packets = vec![first, vorbis_comment()];
+    packets.extend(frames);
+
+    let header_count = 2;
+    let total = packets.len();
+    for (index, packet) in packets.into_iter().enumerate() {
+        let end = if index + 1 == total {
+            PacketWriteEndInfo::EndStream
+        } else if index < header_count {
+            PacketWriteEndInfo::EndPage
+        } else {
+            PacketWriteEndInfo::NormalPacket
+        };
+        let granule = if index < header_count {
+            0
+        } else {
+            ((index + 1 - header_count) * BLOCK_SIZE).min(samples.len()) as u64
+        };
+
+        // Schreibt nur in einen Vec und kann daher nicht fehlschlagen
+        let _ = writer.write_packet(packet.into_boxed_slice(), OGG_SERIAL, end, granule);
+    }
+
+    writer.into_inner()
+}
+
+fn stream_info(total_samples: usize, last: bool) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+    bits.write(u64::from(last), 1);
+    bits.write(0, 7);
+    bits.write(34, 24);
+
+    bits.write(BLOCK_SIZE as u64, 16);
+    bits.write(BLOCK_SIZE as u64, 16);
+    // Unbekannte Framegroessen
+    bits.write(0, 24);
+    bits.write(0, 24);
+    bits.write(u64::from(SAMPLE_RATE), 20);
+    bits.write(0, 3);
+    bits.write(u64::from(BITS_PER_SAMPLE - 1), 5);
+    bits.write(total_samples as u64, 36);
+    // Ohne MD5-Summe
+    bits.write(0, 64);
+    bits.write(0, 64);
+    bits.into_bytes()
+}
+
+fn vorbis_comment() -> Vec<u8> {
+    let vendor = b"cw-listen-trainer";
+    let mut content = (vendor.len() as u32).to_le_bytes().to_vec();
+    content.extend_from_slice(vendor);
+    content.extend_from_slice(&0u32.to_le_bytes());
+
+    let mut block = vec![0x80 | 4];
+    block.extend_from_slice(&(content.len() as u32).to_be_bytes()[1..]);
+    block.extend(content);
+    block
+}
+
+fn frames(samples: &[i16]) -> Vec<Vec<u8>> {
+    samples
+        .chunks(BLOCK_SIZE)
+        .enumerate()
+        .map(|(number, block)| frame(number as u64, block))
+        .collect()
+}
+
+fn frame(number: u64, block: &[i16]) -> Vec<u8> {
+    let mut bits = BitWriter::default();
+
+    bits.write(0b11_1111_1111_1110, 14);
+    bits.write(0, 1);
+    // Feste Blockgroesse
+    bits.write(0, 1);
+    // Blockgroesse steht als 16 Bit am Ende des Headers
+    bits.write(0b0111, 4);
+    // Abtastrate aus STREAMINFO
+    bits.write(0b0000, 4);
+    // Mono
+    bits.write(0b0000, 4);
+    // 16 Bit
+    bits.write(0b100, 3);
+    bits.write(0, 1);
+    for byte in utf8_number(number) {
+        bits.write(u64::from(byte), 8);
+    }
+    bits.write(block.len() as u64 - 1, 16);
+
+    let header = bits.bytes.clone();
+    bits.write(u64::from(crc8(&header)), 8);
+
+    subframe(&mut bits, block);
+    bits.align();
+
+    let mut bytes = bits.into_bytes();
+    let crc = crc16(&bytes);
+    bytes.extend_from_slice(&crc.to_be_bytes());
+    bytes
+}
+
+fn subframe(bits: &mut BitWriter, block: &[i16]) {
+    if block.iter().all(|sample| *sample == block[0]) {
+        subframe_header(bits, 0b000000);
+        bits.write(block[0] as u16 as u64, BITS_PER_SAMPLE);
+        return;
+    }
+
+    // Vorhersage zweiter Ordnung; Restfehler Rice-kodiert, wenn das kuerzer ist als unkomprimiert
+    if block.len() > 2 {
+        let residuals: Vec<i32> = (2..block.len())
+            .map(|i| i32::from(block[i]) - 2 * i32::from(block[i - 1]) + i32::from(block[i - 2]))
+            .collect();
+        let (parameter, size) = rice_parameter(&residuals);
+
+        if size < (block.len() as u64 - 2) * u64::from(BITS_PER_SAMPLE) {
+            subframe_header(bits, 0b001010);
+            for sample in &block[..2] {
+                bits.write(*sample as u16 as u64, BITS_PER_SAMPLE);
+            }
+            // Rice mit 4-Bit-Parameter, eine Partition
+            bits.write(0b00, 2);
+            bits.write(0, 4);
+            bits.write(u64::from(parameter), 4);
+            for residual in residuals {
+                let folded = ((residual << 1) ^ (residual >> 31)) as u32;
+                bits.write_unary(folded >> parameter);
+                bits.write(u64::from(folded) & ((1 << parameter) - 1), parameter);
+            }
+            return;
+        }
+    }
+
+    subframe_header(bits, 0b000001);
+    for sample in block {
+        bits.write(*sample as u16 as u64, BITS_PER_SAMPLE);
+    }
+}
+
+// Fuellbit, 6 Bit Typ (konstant, unkomprimiert oder feste Vorhersage mit Ordnung) und keine verschwendeten Bits
+fn subframe_header(bits: &mut BitWriter, kind: u64) {
+    bits.write(0, 1);
+    bits.write(kind, 6);
+    bits.write(0, 1);
+}
+
+/// Bester Rice-Parameter und die Anzahl Bits, die die Restfehler damit brauchen.
+fn rice_parameter(residuals: &[i32]) -> (u32, u64) {
+    (0..15)
+        .map(|parameter| {
+            let size = residuals
+                .iter()
+                .map(|residual| {
+                    let folded = ((residual << 1) ^ (residual >> 31)) as u32;
+                    u64::from(folded >> parameter) + 1 + u64::from(parameter)
+                })
+                .sum::<u64>();
+            (parameter, size + 10)
+        })
+        .min_by_key(|(_, size)| *size)
+        .unwrap_or((0, u64::MAX))
+}
+
+/// Framenummer in der UTF-8-artigen Kodierung von FLAC.
+fn utf8_number(number: u64) -> Vec<u8> {
+    if number < 0x80 {
+        return vec![number as u8];
+    }
+
+    let mut continuation = Vec::new();
+    let mut rest = number;
+    let mut limit = 0x20;
+    while rest >= limit {
+        continuation.push(0x80 | (rest & 0x3f) as u8);
+        rest >>= 6;
+        limit >>= 1;
+    }
+
+    let length = continuation.len() + 1;
+    let prefix = !(0xffu8 >> length);
+    let mut bytes = vec![prefix | rest as u8];
+    bytes.extend(continuation.into_iter().rev());
+    bytes
+}
+
+fn crc8(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |mut crc, byte| {
+        crc ^= byte;
+        for _ in 0..8 {
+            crc = if crc & 0x80 != 0 { (crc << 1) ^ 0x07 } else { crc << 1 };
+        }
+        crc
+    })
+}
+
+fn crc16(bytes: &[u8]) -> u16 {
+    bytes.iter().fold(0u16, |mut crc, byte| {
+        crc ^= u16::from(*byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 { (crc << 1) ^ 0x8005 } else { crc << 1 };
+        }
+        crc
+    })
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    current: u64,
+    used: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, value: u64, count: u32) {
+        for bit in (0..count).rev() {
+            self.current = (self.current << 1) | ((value >> bit) & 1);
+            self.used += 1;
+            if self.used == 8 {
+                self.bytes.push(self.current as u8);
+                self.current = 0;
+                self.used = 0;
+            }
+        }
+    }
+
+    fn write_unary(&mut self, zeros: u32) {
+        for _ in 0..zeros {
+            self.write(0, 1);
+        }
+        self.write(1, 1);
+    }
+
+    fn align(&mut self) {
+        if self.used > 0 {
+            self.write(0, 8 - self.used);
+        }
+    }
+
+    fn into_bytes(mut self) -> Vec<u8> {
+        self.align();
+        self.bytes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use ogg::PacketReader;
+
+    use super::*;
+    use crate::morse::{self, Speed, DEFAULT_TONE_HZ, DEFAULT_WPM};
+
+    /// Ein Morsezeichen mit Stille davor, damit konstante, vorhergesagte und kurze Bloecke vorkommen.
+    fn tone() -> Vec<i16> {
+        let speed = Speed {
+            wpm: DEFAULT_WPM,
+            farnsworth: None,
+        };
+        let mut samples = vec![0i16; BLOCK_SIZE + 17];
+        samples.extend(morse::render("-.-.", speed, DEFAULT_TONE_HZ).into_iter().map(morse::quantize));
+        samples
+    }
+
+    fn decode(bytes: &[u8]) -> (claxon::metadata::StreamInfo, Vec<i16>) {
+        let mut reader = claxon::FlacReader::new(Cursor::new(bytes)).expect("gueltiger FLAC-Header");
+        let samples = reader.samples().map(|sample| sample.expect("gueltiger Frame") as i16).collect();
+        (reader.streaminfo(), samples)
+    }
+
+    #[test]
+    fn flac_round_trip() {
+        let samples = tone();
+        let (info, decoded) = decode(&encode(&samples));
+
+        assert_eq!(info.sample_rate, SAMPLE_RATE);
+        assert_eq!(info.channels, 1);
+        assert_eq!(info.samples, Some(samples.len() as u64));
+        assert_eq!(decoded, samples);
+    }
+
+    #[test]
+    fn extreme_samples_round_trip() {
+        let samples: Vec<i16> = (0..3000).map(|i| if i % 2 == 0 { i16::MAX } else { i16::MIN }).collect();
+        assert_eq!(decode(&encode(&samples)).1, samples);
+    }
+
+    #[test]
+    fn ogg_pages_are_valid() {
+        let samples = tone();
+        let bytes = encode_ogg(&samples);
+
+        let mut granules = Vec::new();
+        let mut rest = &bytes[..];
+        while !rest.is_empty() {
+            assert_eq!(&rest[..4], b"OggS");
+            let segments = usize::from(rest[26]);
+            let length = 27 + segments + rest[27..27 + segments].iter().map(|size| usize::from(*size)).sum::<usize>();
+            let page = &rest[..length];
+
+            let mut zeroed = page.to_vec();
+            zeroed[22..26].fill(0);
+            assert_eq!(u32::from_le_bytes(page[22..26].try_into().unwrap()), ogg_crc(&zeroed));
+            assert_eq!(u32::from_le_bytes(page[14..18].try_into().unwrap()), OGG_SERIAL);
+            granules.push(u64::from_le_bytes(page[6..14].try_into().unwrap()));
+            rest = &rest[length..];
+        }
+
+        // Zwei Header-Seiten ohne Samples, danach steigend bis zur Gesamtlaenge
+        assert_eq!(granules[..2], [0, 0]);
+        assert!(granules.windows(2).all(|pair| pair[0] <= pair[1]));
+        assert_eq!(granules.last(), Some(&(samples.len() as u64)));
+    }
+
+    #[test]
+    fn ogg_round_trip() {
+        let samples = tone();
+        let mut reader = PacketReader::new(Cursor::new(encode_ogg(&samples)));
+
+        // Ohne die Ogg-Zuordnung (0x7f, "FLAC", Version, Anzahl Header) bleibt ein normaler FLAC-Strom
+        let first = reader.read_packet_expected().expect("erstes Paket");
+        assert_eq!(&first.data[..5], b"\x7fFLAC");
+        let mut flac = first.data[9..].to_vec();
+        while let Some(packet) = reader.read_packet().expect("gueltige Ogg-Seite") {
+            flac.extend(packet.data);
+        }
+
+        assert_eq!(decode(&flac).1, samples);
+    }
+
+    fn ogg_crc(bytes: &[u8]) -> u32 {
+        bytes.iter().fold(0u32, |mut crc, byte| {
+            crc ^= u32::from(*byte) << 24;
+            for _ in 0..8 {
+                crc = if crc & 0x8000_0000 != 0 { (crc << 1) ^ 0x04c1_1db7 } else { crc << 1 };
+            }
+            crc
+        })
+    }
+}
@@ -0,0 +1,8 @@
+/// Bringt eine Eingabe in die Form, in der sie verglichen und gespeichert wird.
+pub fn normalize(answer: &str) -> String {
+    answer.trim().to_lowercase()
+}
+
+pub fn is_correct(expected: &str, typed: &str) -> bool {
+    normalize(typed) == expected.to_lowercase()
+}
@@ -0,0 +1,122 @@
+//! Texte der Oberflaeche ueber Fluent; die Uebersetzungen liegen in `locales/<sprache>.ftl`.
+
+use std::sync::OnceLock;
+
+use fluent_bundle::{concurrent::FluentBundle, FluentResource};
+use serde::{Deserialize, Serialize};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentArgs;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    De,
+    En,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::De, Language::En];
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::De => "de",
+            Language::En => "en",
+        }
+    }
+
+    /// Name der Sprache in ihr selbst, fuer die Auswahl.
+    pub fn name(self) -> &'static str {
+        match self {
+            Language::De => "Deutsch",
+            Language::En => "English",
+        }
+    }
+
+    /// Erkennt z.B. "de-AT" oder "en_US.UTF-8".
+    pub fn from_locale(locale: &str) -> Option<Self> {
+        let code = locale.get(..2)?.to_lowercase();
+        Self::ALL.into_iter().find(|language| language.code() == code)
+    }
+
+    /// Sprache des Systems; Deutsch, wenn sie nicht unterstuetzt wird.
+    pub fn system() -> Self {
+        sys_locale::get_locale()
+            .and_then(|locale| Self::from_locale(&locale))
+            .unwrap_or(Language::De)
+    }
+
+    fn source(self) -> &'static str {
+        match self {
+            Language::De => include_str!("../locales/de.ftl"),
+            Language::En => include_str!("../locales/en.ftl"),
+        }
+    }
+}
+
+struct Catalog {
+    language: Language,
+    bundle: FluentBundle<FluentResource>,
+    // Fuer Texte, die in der gewaehlten Sprache fehlen
+    fallback: FluentBundle<FluentResource>,
+}
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+fn bundle(language: Language) -> FluentBundle<FluentResource> {
+    let id: LanguageIdentifier = language.code().parse().expect("gueltiger Sprachcode");
+    let resource = FluentResource::try_new(language.source().to_string()).expect("gueltige ftl-Datei");
+
+    let mut bundle = FluentBundle::new_concurrent(vec![id]);
+    // Sonst landen Unicode-Steuerzeichen um jeden Platzhalter im Text
+    bundle.set_use_isolating(false);
+    bundle.add_resource(resource).expect("keine doppelten Texte");
+    bundle
+}
+
+/// Legt die Sprache fest; `None` nimmt die des Systems. Wirkt nur beim ersten Aufruf.
+pub fn init(language: Option<Language>) -> Language {
+    catalog_for(language).language
+}
+
+fn catalog_for(language: Option<Language>) -> &'static Catalog {
+    CATALOG.get_or_init(|| {
+        let language = language.unwrap_or_else(Language::system);
+        Catalog {
+            language,
+            bundle: bundle(language),
+            fallback: bundle(Language::De),
+        }
+    })
+}
+
+pub fn current() -> Language {
+    catalog_for(None).language
+}
+
+/// Liefert den Text zu `id`, bei unbekannten Texten die `id` selbst.
+pub fn translate(id: &str, args: Option<&FluentArgs>) -> String {
+    let catalog = catalog_for(None);
+
+    for bundle in [&catalog.bundle, &catalog.fallback] {
+        if let Some(pattern) = bundle.get_message(id).and_then(|message| message.value()) {
+            let mut errors = Vec::new();
+            return bundle.format_pattern(pattern, args, &mut errors).into_owned();
+        }
+    }
+
+    id.to_string()
+}
+
+/// `tr!("id")` oder `tr!("id", name = wert, ...)`
+#[macro_export]
+macro_rules! tr {
+    ($id:expr) => {
+        $crate::i18n::translate($id, None)
+    };
+    ($id:expr, $($name:ident = $value:expr),+ $(,)?) => {{
+        let mut args = $crate::i18n::FluentArgs::new();
+        $(args.set(stringify!($name), $value);)+
+        $crate::i18n::translate($id, Some(&args))
+    }};
+}
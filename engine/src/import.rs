@@ -0,0 +1,271 @@
+//! Uebungsmaterial aus den Dateiformaten anderer Morse-Trainer.
+
+use std::{fmt, fs, path::Path, str::FromStr};
+
+use rand::{rngs::StdRng, seq::SliceRandom, SeedableRng};
+
+use crate::library::LibraryError;
+use crate::morse;
+use crate::training_mode::name_of;
+
+/// Wie eine Datei mit Woertern, Gruppen oder Rufzeichen gelesen wird.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ItemFormat {
+    /// Ein Wort bzw. eine Wendung pro Zeile, in zufaelliger Reihenfolge
+    Words,
+    /// Gruppen wie bei LCWO, durch Leerzeichen getrennt und in der Reihenfolge der Datei
+    Groups,
+    /// Rufzeichen im Format von Super Check Partial (`MASTER.SCP`), in zufaelliger Reihenfolge
+    Callsigns,
+}
+
+impl ItemFormat {
+    /// `.scp` sind Rufzeichen, alles andere wird als Wortliste gelesen.
+    pub fn guess(path: &Path) -> Self {
+        let scp = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("scp"));
+        if scp { ItemFormat::Callsigns } else { ItemFormat::Words }
+    }
+
+    fn shuffled(self) -> bool {
+        self != ItemFormat::Groups
+    }
+}
+
+impl FromStr for ItemFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "words" => Ok(ItemFormat::Words),
+            "groups" => Ok(ItemFormat::Groups),
+            "callsigns" | "scp" => Ok(ItemFormat::Callsigns),
+            _ => Err("words, groups, callsigns".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ItemFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ItemFormat::Words => "words",
+            ItemFormat::Groups => "groups",
+            ItemFormat::Callsigns => "callsigns",
+        })
+    }
+}
+
+// Leere Zeilen und Kommentare mit '#' zaehlen nicht
+fn lines(content: &str) -> impl Iterator<Item = &str> {
+    content
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+}
+
+fn read(path: &Path) -> Result<String, LibraryError> {
+    let bytes = fs::read(path).map_err(|err| LibraryError::FileUnreadable(path.to_path_buf(), err))?;
+    Ok(String::from_utf8_lossy(&bytes).into_owned())
+}
+
+/// Liest Woerter, Gruppen oder Rufzeichen. Eintraege mit Zeichen, fuer die `allows` nicht gilt,
+/// fallen weg; `seed` macht die zufaellige Reihenfolge reproduzierbar.
+pub fn load_items(
+    path: &Path,
+    format: ItemFormat,
+    allows: impl Fn(&str) -> bool,
+    seed: Option<u64>,
+) -> Result<Vec<String>, LibraryError> {
+    let content = read(path)?;
+
+    let items: Vec<String> = match format {
+        ItemFormat::Words | ItemFormat::Callsigns => lines(&content)
+            .map(|line| line.split_whitespace().collect::<Vec<_>>().join(" "))
+            .collect(),
+        ItemFormat::Groups => lines(&content).flat_map(str::split_whitespace).map(str::to_string).collect(),
+    };
+
+    let mut items: Vec<String> = items
+        .into_iter()
+        .map(|item| item.to_lowercase())
+        .filter(|item| {
+            item.chars().filter(|c| !c.is_whitespace()).all(|c| {
+                let name = name_of(&c.to_string());
+                morse::code(&name).is_some() && allows(&name)
+            })
+        })
+        .collect();
+
+    if items.is_empty() {
+        return Err(LibraryError::NothingToSend(path.to_path_buf()));
+    }
+
+    if format.shuffled() {
+        let mut rng = match seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        };
+        items.shuffle(&mut rng);
+    }
+
+    Ok(items)
+}
+
+/// Liest eine eigene Koch-Reihenfolge, z.B. "KMURES..." oder durch Leerzeichen getrennt
+/// mit Namen wie "ar" oder "<SK>". Liefert die Zeichennamen ohne Doppelte; unbekannte Zeichen fallen weg.
+pub fn load_koch_order(path: &Path) -> Result<Vec<String>, LibraryError> {
+    let content = read(path)?;
+    let mut order: Vec<String> = Vec::new();
+
+    for token in lines(&content).flat_map(str::split_whitespace) {
+        let token = token.trim_start_matches('<').trim_end_matches('>');
+        let name = name_of(token);
+
+        let names = if morse::code(&name).is_some() {
+            vec![name]
+        } else {
+            token.chars().map(|c| name_of(&c.to_string())).collect()
+        };
+
+        for name in names {
+            if morse::code(&name).is_some() && !order.contains(&name) {
+                order.push(name);
+            }
+        }
+    }
+
+    if order.is_empty() {
+        return Err(LibraryError::NothingToSend(path.to_path_buf()));
+    }
+
+    Ok(order)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    fn file(content: &str) -> PathBuf {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+        let dir = std::env::temp_dir().join("cw-engine-import").join(std::process::id().to_string());
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join(format!("{}.txt", COUNT.fetch_add(1, Ordering::Relaxed)));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn items(content: &str, format: ItemFormat) -> Result<Vec<String>, LibraryError> {
+        let mut items = load_items(&file(content), format, |_| true, Some(1))?;
+        if format.shuffled() {
+            items.sort();
+        }
+        Ok(items)
+    }
+
+    #[test]
+    fn word_lists() {
+        let cases: [(&str, &[&str]); 6] = [
+            ("cq\nde\ntest\n", &["cq", "de", "test"]),
+            ("# Kommentar\n\n  cq  \n\n# noch einer\nde\n", &["cq", "de"]),
+            ("CQ\nTest\nrst 599\n", &["cq", "rst 599", "test"]),
+            ("tnx  fer   qso\n", &["tnx fer qso"]),
+            ("gruesse\ngrüße\nok?\n", &["gruesse", "ok?"]),
+            ("73\n\r\nhi\r\n", &["73", "hi"]),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(items(content, ItemFormat::Words).unwrap(), expected, "{:?}", content);
+        }
+    }
+
+    #[test]
+    fn lcwo_groups() {
+        let cases: [(&str, &[&str]); 4] = [
+            ("KMRSU EOAIT\nKKMMR\n", &["kmrsu", "eoait", "kkmmr"]),
+            ("# Lektion 5\n\nabcde   fghij\n", &["abcde", "fghij"]),
+            ("ab%cd efghi\n", &["efghi"]),
+            ("12345 ?/=.,\n", &["12345", "?/=.,"]),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(items(content, ItemFormat::Groups).unwrap(), expected, "{:?}", content);
+        }
+    }
+
+    #[test]
+    fn master_scp() {
+        let cases: [(&str, &[&str]); 4] = [
+            ("# Super Check Partial\nDL1ABC\nW1AW\n", &["dl1abc", "w1aw"]),
+            ("\n  DK0XX  \n\n", &["dk0xx"]),
+            ("EA8/DL2XYZ\nDL2XYZ/P\n", &["dl2xyz/p", "ea8/dl2xyz"]),
+            ("DL1ÄB\nDL3#\nG4ABC\n", &["g4abc"]),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(items(content, ItemFormat::Callsigns).unwrap(), expected, "{:?}", content);
+        }
+    }
+
+    #[test]
+    fn deselected_characters_drop_the_item() {
+        let path = file("kmr\nkmx\nrrr\n");
+        let items = load_items(&path, ItemFormat::Groups, |name| name != "x", None).unwrap();
+        assert_eq!(items, ["kmr", "rrr"]);
+    }
+
+    #[test]
+    fn nothing_left_is_an_error() {
+        for content in ["", "\n\n", "# nur ein Kommentar\n", "äöü\n"] {
+            for format in [ItemFormat::Words, ItemFormat::Groups, ItemFormat::Callsigns] {
+                assert!(matches!(items(content, format), Err(LibraryError::NothingToSend(_))), "{:?}", content);
+            }
+        }
+    }
+
+    #[test]
+    fn seed_makes_the_order_reproducible() {
+        let path = file(&(0..50).map(|i| format!("w{}", i)).collect::<Vec<_>>().join("\n"));
+        let first = load_items(&path, ItemFormat::Words, |_| true, Some(7)).unwrap();
+        assert_eq!(load_items(&path, ItemFormat::Words, |_| true, Some(7)).unwrap(), first);
+    }
+
+    #[test]
+    fn koch_orders() {
+        let cases: [(&str, &[&str]); 7] = [
+            ("KMURES\n", &["k", "m", "u", "r", "e", "s"]),
+            ("k m u r\n", &["k", "m", "u", "r"]),
+            ("# Eigene Reihenfolge\n\nKM\n\nUR\n", &["k", "m", "u", "r"]),
+            ("KMK MU\n", &["k", "m", "u"]),
+            ("K M <SK> ar\n", &["k", "m", "sk", "ar"]),
+            ("K ? / =\n", &["k", "fragezeichen", "schraestrich", "ist_gleich"]),
+            ("K%M Ä\n", &["k", "m"]),
+        ];
+        for (content, expected) in cases {
+            assert_eq!(load_koch_order(&file(content)).unwrap(), expected, "{:?}", content);
+        }
+    }
+
+    #[test]
+    fn empty_koch_order_is_an_error() {
+        for content in ["", "# nur ein Kommentar\n", "%&\n"] {
+            assert!(matches!(load_koch_order(&file(content)), Err(LibraryError::NothingToSend(_))), "{:?}", content);
+        }
+    }
+
+    #[test]
+    fn missing_file_is_an_error() {
+        let path = Path::new("gibt-es-nicht.txt");
+        assert!(matches!(load_koch_order(path), Err(LibraryError::FileUnreadable(..))));
+        assert!(load_items(path, ItemFormat::Words, |_| true, None).is_err());
+    }
+
+    #[test]
+    fn format_from_extension() {
+        assert_eq!(ItemFormat::guess(Path::new("MASTER.SCP")), ItemFormat::Callsigns);
+        assert_eq!(ItemFormat::guess(Path::new("master.scp")), ItemFormat::Callsigns);
+        assert_eq!(ItemFormat::guess(Path::new("woerter.txt")), ItemFormat::Words);
+        assert_eq!(ItemFormat::guess(Path::new("groups")), ItemFormat::Words);
+    }
+}
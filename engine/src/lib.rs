@@ -0,0 +1,44 @@
+//! Trainings-Engine des CW Listen Trainers ohne Abhaengigkeit zu Bevy.
+//!
+//! `TrainingSession` bildet den kompletten Ablauf (Abspielen, Antwort, Pause,
+//! Wiederholungen) als Zustandsmaschine ab, die nur ueber `SessionEvent`s und
+//! verstrichene Zeit weitergeschaltet wird. Was dabei hoerbar oder sichtbar
+//! werden soll, meldet sie als `Effect` bzw. `Prompt` an das Frontend.
+
+pub mod accessibility;
+pub mod alignment;
+pub mod audio;
+pub mod config;
+pub mod confusion;
+pub mod export;
+pub mod flac;
+pub mod grading;
+pub mod i18n;
+pub mod import;
+pub mod library;
+pub mod loopback;
+pub mod morse;
+pub mod profile;
+pub mod recording;
+#[cfg(feature = "rodio")]
+pub mod rodio_backend;
+pub mod session;
+pub mod stats;
+pub mod summary;
+#[cfg(any(test, feature = "test-util"))]
+pub mod test_util;
+pub mod text;
+pub mod training_mode;
+
+pub use audio::{AudioBackend, AudioEvent, SilentBackend};
+pub use i18n::Language;
+pub use config::{Config, ConfigError};
+pub use library::{LibraryError, Signal, Sound, SoundLibrary};
+pub use loopback::LoopbackBackend;
+pub use profile::Profile;
+#[cfg(feature = "rodio")]
+pub use rodio_backend::RodioBackend;
+pub use session::{CopyBehind, Effect, Grade, Limits, PlayState, Prompt, SessionEvent, Timings, TrainingSession};
+pub use summary::Summary;
+pub use text::TextPractice;
+pub use training_mode::TrainingMode;
@@ -0,0 +1,154 @@
+use std::{error::Error, fmt, fs, io, path::{Path, PathBuf}};
+
+use crate::morse::{self, Speed};
+use crate::tr;
+
+/// Dateiendungen, die als Zeichen-Sounds erkannt werden.
+pub const EXTENSIONS: [&str; 3] = ["mp3", "ogg", "wav"];
+
+/// Ein abspielbares Zeichen; `name` ist der Dateiname ohne Endung und zugleich die Loesung.
+#[derive(Clone, PartialEq, Debug)]
+pub struct Sound {
+    pub path: PathBuf,
+    pub name: String,
+    pub signal: Signal,
+}
+
+impl Sound {
+    pub fn new(path: PathBuf, name: impl Into<String>) -> Self {
+        Self { path, name: name.into(), signal: Signal::default() }
+    }
+}
+
+/// Wie ein Zeichen klingt; bei fertigen Aufnahmen unbekannt.
+#[derive(Clone, Copy, Default, PartialEq, Debug)]
+pub struct Signal {
+    pub wpm: Option<f32>,
+    pub pitch_hz: Option<f32>,
+    pub noise_level: Option<f32>,
+}
+
+impl Signal {
+    /// Selbst erzeugter Ton ist immer rauschfrei.
+    pub fn synthesized(speed: Speed, tone_hz: f32) -> Self {
+        Self { wpm: Some(speed.wpm), pitch_hz: Some(tone_hz), noise_level: Some(0.0) }
+    }
+}
+
+#[derive(Debug)]
+pub enum LibraryError {
+    NotFound(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Empty(PathBuf),
+    Unwritable(PathBuf, io::Error),
+    FileUnreadable(PathBuf, io::Error),
+    /// Nach Anwenden der Zeichenauswahl bleibt von einem Text nichts uebrig
+    NothingToSend(PathBuf),
+}
+
+impl fmt::Display for LibraryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LibraryError::NotFound(dir) => write!(f, "{}", tr!("error-dir-not-found", dir = dir.display().to_string())),
+            LibraryError::Unreadable(dir, err) => write!(
+                f,
+                "{}",
+                tr!("error-dir-unreadable", dir = dir.display().to_string(), error = err.to_string())
+            ),
+            LibraryError::Empty(dir) => write!(f, "{}", tr!("error-no-audio-files", dir = dir.display().to_string())),
+            LibraryError::Unwritable(path, err) => write!(
+                f,
+                "{}",
+                tr!("error-unwritable", path = path.display().to_string(), error = err.to_string())
+            ),
+            LibraryError::FileUnreadable(path, err) => write!(
+                f,
+                "{}",
+                tr!("error-unreadable", path = path.display().to_string(), error = err.to_string())
+            ),
+            LibraryError::NothingToSend(path) => write!(f, "{}", tr!("error-nothing-to-send", path = path.display().to_string())),
+        }
+    }
+}
+
+impl Error for LibraryError {}
+
+/// Alle Zeichen, aus denen eine Runde gewaehlt werden kann.
+#[derive(Clone, Default, Debug)]
+pub struct SoundLibrary {
+    sounds: Vec<Sound>,
+}
+
+impl SoundLibrary {
+    pub fn scan(dir: impl AsRef<Path>) -> Result<Self, LibraryError> {
+        let dir = dir.as_ref();
+
+        if !dir.exists() {
+            return Err(LibraryError::NotFound(dir.to_path_buf()));
+        }
+
+        let entries = fs::read_dir(dir).map_err(|err| LibraryError::Unreadable(dir.to_path_buf(), err))?;
+
+        let mut sounds: Vec<Sound> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| EXTENSIONS.contains(&ext))
+            })
+            .map(|path| {
+                let name = path
+                    .file_stem()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or("Unbekannt")
+                    .to_string();
+                Sound::new(path, name)
+            })
+            .collect();
+
+        if sounds.is_empty() {
+            return Err(LibraryError::Empty(dir.to_path_buf()));
+        }
+
+        sounds.sort_by(|a, b| a.path.cmp(&b.path));
+        Ok(Self { sounds })
+    }
+
+    /// Erzeugt alle bekannten Zeichen als WAV-Dateien in `dir` statt fertige Sounds zu laden.
+    pub fn synthesize(dir: impl AsRef<Path>, speed: Speed, tone_hz: f32) -> Result<Self, LibraryError> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir).map_err(|err| LibraryError::Unwritable(dir.to_path_buf(), err))?;
+
+        let mut sounds = Vec::new();
+        for name in morse::names() {
+            let path = dir.join(format!("{}.wav", name));
+            let code = morse::code(name).unwrap_or_default();
+
+            if !path.exists() {
+                let bytes = morse::wav_bytes(&morse::render(code, speed, tone_hz));
+                fs::write(&path, bytes).map_err(|err| LibraryError::Unwritable(path.clone(), err))?;
+            }
+            sounds.push(Sound { path, name: name.to_string(), signal: Signal::synthesized(speed, tone_hz) });
+        }
+
+        Ok(Self { sounds })
+    }
+
+    pub fn from_sounds(sounds: Vec<Sound>) -> Self {
+        Self { sounds }
+    }
+
+    pub fn sounds(&self) -> &[Sound] {
+        &self.sounds
+    }
+
+    pub fn names(&self) -> Vec<String> {
+        self.sounds.iter().map(|sound| sound.name.clone()).collect()
+    }
+
+    /// Sucht ein Zeichen ohne Ruecksicht auf Gross-/Kleinschreibung.
+    pub fn find(&self, name: &str) -> Option<&Sound> {
+        self.sounds.iter().find(|sound| sound.name.eq_ignore_ascii_case(name))
+    }
+}
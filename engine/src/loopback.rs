@@ -0,0 +1,261 @@
+//! Wiedergabe ohne Soundkarte: eigene Dekoder, eine virtuelle Uhr und auf Wunsch ein Mitschnitt.
+//! Braucht kein Audiogeraet und laeuft daher auch dort, wo es kein ALSA gibt.
+
+use std::{fs, io::{self, Cursor}, time::Duration};
+
+use lewton::inside_ogg::OggStreamReader;
+use symphonia::core::{
+    audio::SampleBuffer, codecs::DecoderOptions, errors::Error, formats::FormatOptions, io::MediaSourceStream,
+    meta::MetadataOptions, probe::Hint,
+};
+
+use crate::audio::{AudioBackend, AudioEvent};
+use crate::library::Sound;
+
+/// Dekodierte Samples eines Sounds, die Kanaele abwechselnd.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Samples {
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub data: Vec<f32>,
+}
+
+impl Samples {
+    /// Erkennt WAV und Ogg Vorbis am Anfang der Datei, alles andere wird als MP3 gelesen.
+    pub fn decode(bytes: &[u8]) -> Result<Self, String> {
+        match bytes.get(..4) {
+            Some(b"RIFF") => decode_wav(bytes),
+            Some(b"OggS") => decode_vorbis(bytes),
+            _ => decode_mp3(bytes),
+        }
+    }
+
+    /// Leere Aufnahme im selben Format, z.B. zum Mitschneiden einer Wiedergabe.
+    pub fn empty_like(&self) -> Self {
+        Self {
+            channels: self.channels,
+            sample_rate: self.sample_rate,
+            data: Vec::new(),
+        }
+    }
+
+    pub fn duration(&self) -> Duration {
+        let frames = self.data.len() / self.channels.max(1) as usize;
+        Duration::from_secs_f64(frames as f64 / self.sample_rate.max(1) as f64)
+    }
+}
+
+fn decode_wav(bytes: &[u8]) -> Result<Samples, String> {
+    let mut reader = hound::WavReader::new(Cursor::new(bytes)).map_err(|err| err.to_string())?;
+    let spec = reader.spec();
+
+    let data: Result<Vec<f32>, _> = match spec.sample_format {
+        hound::SampleFormat::Int => {
+            let scale = (1u64 << (spec.bits_per_sample - 1)) as f32;
+            reader.samples::<i32>().map(|sample| sample.map(|sample| sample as f32 / scale)).collect()
+        }
+        hound::SampleFormat::Float => reader.samples::<f32>().collect(),
+    };
+
+    Ok(Samples {
+        channels: spec.channels,
+        sample_rate: spec.sample_rate,
+        data: data.map_err(|err| err.to_string())?,
+    })
+}
+
+fn decode_vorbis(bytes: &[u8]) -> Result<Samples, String> {
+    let mut reader = OggStreamReader::new(Cursor::new(bytes)).map_err(|err| err.to_string())?;
+    let mut data = Vec::new();
+
+    while let Some(packet) = reader.read_dec_packet_itl().map_err(|err| err.to_string())? {
+        data.extend(packet.into_iter().map(|sample: i16| sample as f32 / 32768.0));
+    }
+
+    Ok(Samples {
+        channels: u16::from(reader.ident_hdr.audio_channels),
+        sample_rate: reader.ident_hdr.audio_sample_rate,
+        data,
+    })
+}
+
+fn decode_mp3(bytes: &[u8]) -> Result<Samples, String> {
+    let stream = MediaSourceStream::new(Box::new(Cursor::new(bytes.to_vec())), Default::default());
+    let mut hint = Hint::new();
+    hint.with_extension("mp3");
+
+    let mut format = symphonia::default::get_probe()
+        .format(&hint, stream, &FormatOptions::default(), &MetadataOptions::default())
+        .map_err(|err| err.to_string())?
+        .format;
+    let track = format.default_track().ok_or_else(|| "no audio track".to_string())?;
+    let track_id = track.id;
+    let mut decoder = symphonia::default::get_codecs()
+        .make(&track.codec_params, &DecoderOptions::default())
+        .map_err(|err| err.to_string())?;
+
+    let mut samples = Samples::default();
+    loop {
+        let packet = match format.next_packet() {
+            Ok(packet) => packet,
+            // So meldet symphonia das Ende der Datei
+            Err(Error::IoError(err)) if err.kind() == io::ErrorKind::UnexpectedEof => break,
+            Err(err) => return Err(err.to_string()),
+        };
+        if packet.track_id() != track_id {
+            continue;
+        }
+
+        let decoded = decoder.decode(&packet).map_err(|err| err.to_string())?;
+        let spec = *decoded.spec();
+        samples.channels = spec.channels.count() as u16;
+        samples.sample_rate = spec.rate;
+
+        let mut buffer = SampleBuffer::<f32>::new(decoded.capacity() as u64, spec);
+        buffer.copy_interleaved_ref(decoded);
+        samples.data.extend_from_slice(buffer.samples());
+    }
+
+    Ok(samples)
+}
+
+/// Wiedergabe ohne Geraet: die Samples gelten als gespielt, sobald genug virtuelle Zeit vergangen ist.
+#[derive(Clone, Debug)]
+pub struct VirtualPlayback {
+    samples: Samples,
+    played: Duration,
+    position: usize,
+}
+
+impl VirtualPlayback {
+    pub fn new(samples: Samples) -> Self {
+        Self {
+            samples,
+            played: Duration::ZERO,
+            position: 0,
+        }
+    }
+
+    /// Laesst `elapsed` vergehen und gibt die Samples zurueck, die dabei gespielt wurden.
+    pub fn advance(&mut self, elapsed: Duration) -> &[f32] {
+        self.played += elapsed;
+
+        let end = if self.is_finished() {
+            self.samples.data.len()
+        } else {
+            let frames = (self.played.as_secs_f64() * self.samples.sample_rate as f64) as usize;
+            (frames * self.samples.channels as usize).min(self.samples.data.len())
+        };
+        let start = self.position;
+        self.position = end.max(start);
+        &self.samples.data[start..self.position]
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.played >= self.samples.duration()
+    }
+
+    pub fn samples(&self) -> &Samples {
+        &self.samples
+    }
+}
+
+/// Ausgabe ohne Soundkarte: spielt in virtueller Zeit, auf Wunsch mit Mitschnitt.
+#[derive(Default, Debug)]
+pub struct LoopbackBackend {
+    playback: Option<VirtualPlayback>,
+    paused: bool,
+    /// Nur mit `capturing`, sonst wuechse der Speicher mit jeder Wiedergabe
+    captured: Option<Vec<Samples>>,
+    error: Option<String>,
+    events: Vec<AudioEvent>,
+}
+
+impl LoopbackBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Wie `new`, behaelt aber alles Gespielte, z.B. fuer Tests.
+    pub fn capturing() -> Self {
+        Self {
+            captured: Some(Vec::new()),
+            ..Self::default()
+        }
+    }
+
+    /// Was bisher ausgegeben wurde, ein Eintrag pro Wiedergabe; ohne `capturing` immer leer.
+    pub fn captured(&self) -> &[Samples] {
+        self.captured.as_deref().unwrap_or_default()
+    }
+}
+
+impl AudioBackend for LoopbackBackend {
+    fn play(&mut self, sound: &Sound) {
+        self.stop();
+
+        let samples = fs::read(&sound.path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| Samples::decode(&bytes))
+            .map_err(|err| format!("{}: {}", sound.path.display(), err));
+
+        match samples {
+            Ok(samples) => {
+                if let Some(captured) = &mut self.captured {
+                    captured.push(samples.empty_like());
+                }
+                self.playback = Some(VirtualPlayback::new(samples));
+                self.error = None;
+
+                self.events.push(AudioEvent::Started);
+                // Ein leerer Sound ist sofort zu Ende
+                if !self.is_playing() {
+                    self.events.push(AudioEvent::Finished);
+                }
+            }
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    fn stop(&mut self) {
+        self.playback = None;
+        self.paused = false;
+    }
+
+    fn pause(&mut self) {
+        self.paused = true;
+    }
+
+    fn resume(&mut self) {
+        self.paused = false;
+    }
+
+    fn is_playing(&self) -> bool {
+        self.playback.as_ref().is_some_and(|playback| !playback.is_finished())
+    }
+
+    fn advance(&mut self, elapsed: Duration) {
+        if self.paused {
+            return;
+        }
+
+        let was_playing = self.is_playing();
+        if let Some(playback) = &mut self.playback {
+            let played = playback.advance(elapsed);
+            if let Some(captured) = self.captured.as_mut().and_then(|captured| captured.last_mut()) {
+                captured.data.extend_from_slice(played);
+            }
+        }
+        if was_playing && !self.is_playing() {
+            self.events.push(AudioEvent::Finished);
+        }
+    }
+
+    fn take_events(&mut self) -> Vec<AudioEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn last_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,236 @@
+use std::{f32::consts::PI, path::PathBuf, time::Duration};
+
+use crate::training_mode::name_of;
+
+pub const SAMPLE_RATE: u32 = 22050;
+pub const DEFAULT_TONE_HZ: f32 = 600.0;
+/// Fuer Texte, wenn keine Geschwindigkeit angegeben ist
+pub const DEFAULT_WPM: f32 = 20.0;
+
+// Ein- und Ausblenden jedes Elements, damit es nicht klickt
+const RAMP: f32 = 0.005;
+
+// Namen wie bei den Sound-Dateien; ein Leerzeichen trennt Buchstaben innerhalb eines Namens
+const CODES: [(&str, &str); 54] = [
+    ("a", ".-"),
+    ("b", "-..."),
+    ("c", "-.-."),
+    ("d", "-.."),
+    ("e", "."),
+    ("f", "..-."),
+    ("g", "--."),
+    ("h", "...."),
+    ("i", ".."),
+    ("j", ".---"),
+    ("k", "-.-"),
+    ("l", ".-.."),
+    ("m", "--"),
+    ("n", "-."),
+    ("o", "---"),
+    ("p", ".--."),
+    ("q", "--.-"),
+    ("r", ".-."),
+    ("s", "..."),
+    ("t", "-"),
+    ("u", "..-"),
+    ("v", "...-"),
+    ("w", ".--"),
+    ("x", "-..-"),
+    ("y", "-.--"),
+    ("z", "--.."),
+    ("0", "-----"),
+    ("1", ".----"),
+    ("2", "..---"),
+    ("3", "...--"),
+    ("4", "....-"),
+    ("5", "....."),
+    ("6", "-...."),
+    ("7", "--..."),
+    ("8", "---.."),
+    ("9", "----."),
+    ("punkt", ".-.-.-"),
+    ("komma", "--..--"),
+    ("fragezeichen", "..--.."),
+    ("ist_gleich", "-...-"),
+    ("schraestrich", "-..-."),
+    ("bindestrich", "-....-"),
+    ("doppelpunkt", "---..."),
+    ("ar", ".-.-."),
+    ("as", ".-..."),
+    ("bk", "-...-.-"),
+    ("bt", "-...-"),
+    ("cq", "-.-. --.-"),
+    ("sk", "...-.-"),
+    ("kn", "-.--."),
+    ("sn", "...-."),
+    ("ve", "...-."),
+    ("ka", "-.-.-"),
+    ("sos", "...---..."),
+];
+
+/// Alle Zeichen, die erzeugt werden koennen.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    CODES.iter().map(|(name, _)| *name)
+}
+
+/// Punkte und Striche eines Zeichens, z.B. ".-" fuer "a".
+pub fn code(name: &str) -> Option<&'static str> {
+    CODES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(name))
+        .map(|(_, code)| *code)
+}
+
+/// Gebegeschwindigkeit nach PARIS, optional mit Farnsworth-Pausen.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct Speed {
+    /// Geschwindigkeit innerhalb eines Zeichens
+    pub wpm: f32,
+    /// Effektive Geschwindigkeit; nur die Pausen zwischen Zeichen und Woertern werden laenger
+    pub farnsworth: Option<f32>,
+}
+
+impl Speed {
+    pub fn dit(&self) -> Duration {
+        Duration::from_secs_f32(1.2 / self.wpm)
+    }
+
+    pub fn char_gap(&self) -> Duration {
+        self.farnsworth_gap(3)
+    }
+
+    pub fn word_gap(&self) -> Duration {
+        self.farnsworth_gap(7)
+    }
+
+    // Verteilung der Zusatzzeit nach ARRL: von 19 Pausen-Dits pro PARIS entfallen 3 bzw. 7 auf eine Luecke
+    fn farnsworth_gap(&self, dits: u32) -> Duration {
+        match self.farnsworth {
+            Some(effective) if effective < self.wpm => {
+                let total = (60.0 * self.wpm - 37.2 * effective) / (effective * self.wpm);
+                Duration::from_secs_f32(total * dits as f32 / 19.0)
+            }
+            _ => self.dit() * dits,
+        }
+    }
+
+    /// Unterverzeichnis, in dem die Zeichen fuer diese Geschwindigkeit zwischengespeichert werden.
+    pub fn cache_dir(&self, tone_hz: f32) -> PathBuf {
+        let base = dirs::cache_dir().unwrap_or_else(std::env::temp_dir);
+        let name = match self.farnsworth {
+            Some(effective) => format!("{}wpm-{}eff-{}hz", self.wpm, effective, tone_hz),
+            None => format!("{}wpm-{}hz", self.wpm, tone_hz),
+        };
+        base.join("cw-listen-trainer").join("morse").join(name)
+    }
+}
+
+/// Erzeugt den Ton fuer `code` als Samples zwischen -1 und 1.
+pub fn render(code: &str, speed: Speed, tone_hz: f32) -> Vec<f32> {
+    let mut samples = Vec::new();
+    let dit = speed.dit();
+
+    let mut previous = ' ';
+
+    for element in code.chars() {
+        match element {
+            '.' | '-' => {
+                if previous != ' ' {
+                    silence(&mut samples, dit);
+                }
+                let length = if element == '.' { dit } else { dit * 3 };
+                tone(&mut samples, length, tone_hz);
+            }
+            ' ' => silence(&mut samples, speed.char_gap()),
+            _ => continue,
+        }
+        previous = element;
+    }
+
+    samples
+}
+
+/// Erzeugt den Ton fuer einen Text aus bekannten Zeichen; Leerzeichen trennen Woerter.
+pub fn render_text(text: &str, speed: Speed, tone_hz: f32) -> Vec<f32> {
+    let words: Vec<Vec<String>> = text
+        .split_whitespace()
+        .map(|word| word.chars().map(|c| name_of(&c.to_string())).collect())
+        .collect();
+    render_words(&words, speed, tone_hz)
+}
+
+/// Wie `render_text`, aber aus Zeichennamen, damit auch Betriebszeichen wie "ar" gehen.
+pub fn render_words(words: &[Vec<String>], speed: Speed, tone_hz: f32) -> Vec<f32> {
+    let mut samples = Vec::new();
+
+    for (index, word) in words.iter().enumerate() {
+        if index > 0 {
+            silence(&mut samples, speed.word_gap());
+        }
+
+        let codes: Vec<&str> = word.iter().filter_map(|name| code(name)).collect();
+        samples.extend(render(&codes.join(" "), speed, tone_hz));
+    }
+
+    samples
+}
+
+/// Pause, z.B. zwischen zwei Eintraegen einer Aufnahme.
+pub fn append_silence(samples: &mut Vec<f32>, length: Duration) {
+    silence(samples, length);
+}
+
+/// Ton mit weichen Flanken, z.B. fuer Rueckmeldetoene.
+pub fn append_tone(samples: &mut Vec<f32>, length: Duration, tone_hz: f32) {
+    tone(samples, length, tone_hz);
+}
+
+fn silence(samples: &mut Vec<f32>, length: Duration) {
+    let count = (length.as_secs_f32() * SAMPLE_RATE as f32) as usize;
+    samples.resize(samples.len() + count, 0.0);
+}
+
+fn tone(samples: &mut Vec<f32>, length: Duration, tone_hz: f32) {
+    let count = (length.as_secs_f32() * SAMPLE_RATE as f32) as usize;
+    let ramp = ((RAMP * SAMPLE_RATE as f32) as usize).min(count / 2).max(1);
+
+    samples.extend((0..count).map(|index| {
+        let edge = index.min(count - 1 - index);
+        let envelope = if edge < ramp {
+            0.5 - 0.5 * (PI * edge as f32 / ramp as f32).cos()
+        } else {
+            1.0
+        };
+        let time = index as f32 / SAMPLE_RATE as f32;
+        0.8 * envelope * (2.0 * PI * tone_hz * time).sin()
+    }));
+}
+
+/// Verpackt Mono-Samples als 16-Bit-PCM-WAV.
+pub fn wav_bytes(samples: &[f32]) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&SAMPLE_RATE.to_le_bytes());
+    bytes.extend_from_slice(&(SAMPLE_RATE * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+
+    for sample in samples {
+        bytes.extend_from_slice(&quantize(*sample).to_le_bytes());
+    }
+
+    bytes
+}
+
+pub fn quantize(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+}
@@ -0,0 +1,205 @@
+use std::{collections::BTreeMap, fs, path::PathBuf, time::{SystemTime, UNIX_EPOCH}};
+use serde::{Deserialize, Serialize};
+
+use crate::confusion::ConfusionMatrix;
+use crate::tr;
+use crate::session::Grade;
+use crate::stats::{day_key, today, AnswerRecord};
+use crate::summary::{SessionRecord, Summary};
+
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Alles, was pro Benutzer ueber mehrere Sitzungen hinweg gespeichert wird.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(default)]
+pub struct ProfileData {
+    pub confusion: ConfusionMatrix,
+    pub history: Vec<AnswerRecord>,
+    /// Trainingszeit in Sekunden pro Tag ("YYYY-MM-DD")
+    pub practice_seconds: BTreeMap<String, f32>,
+    /// Eckdaten aller abgeschlossenen Sitzungen, aelteste zuerst
+    pub sessions: Vec<SessionRecord>,
+}
+
+pub struct Profile {
+    pub name: String,
+    pub data: ProfileData,
+    // Beginn der laufenden Sitzung in `data.history`
+    session_start: usize,
+    // Ohne Datei, z.B. fuer Tests oder wenn eine kaputte Datei nicht beiseitegelegt werden konnte;
+    // `save` tut dann nichts
+    read_only: bool,
+}
+
+impl Profile {
+    /// Laedt das Profil `name`. Eine beschaedigte Datei wird als `<name>.json.bad` beiseitegelegt,
+    /// damit das naechste `save` sie nicht ueberschreibt.
+    pub fn load(name: &str) -> Self {
+        let Some(path) = Self::path(name) else {
+            eprintln!("{}", tr!("error", error = tr!("error-profile-name", name = name)));
+            return Self::temporary(name);
+        };
+
+        let mut read_only = false;
+        let data = match fs::read_to_string(&path) {
+            Ok(content) => serde_json::from_str(&content).unwrap_or_else(|err| {
+                let backup = path.with_extension("json.bad");
+                let message = match fs::rename(&path, &backup) {
+                    Ok(()) => tr!(
+                        "error-profile-corrupt",
+                        path = path.display().to_string(),
+                        error = err.to_string(),
+                        backup = backup.display().to_string()
+                    ),
+                    Err(_) => {
+                        read_only = true;
+                        tr!("error-profile-corrupt-kept", path = path.display().to_string(), error = err.to_string())
+                    }
+                };
+                eprintln!("{}", tr!("error", error = message));
+                ProfileData::default()
+            }),
+            Err(_) => ProfileData::default(),
+        };
+
+        Self {
+            name: name.to_string(),
+            session_start: data.history.len(),
+            data,
+            read_only,
+        }
+    }
+
+    /// Profilnamen werden zum Dateinamen; Pfadtrenner und ".." sind deshalb nicht erlaubt.
+    pub fn is_valid_name(name: &str) -> bool {
+        !name.is_empty() && !name.contains(['/', '\\']) && !name.contains("..")
+    }
+
+    /// Ein leeres Profil, das nie gespeichert wird.
+    pub fn temporary(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            data: ProfileData::default(),
+            session_start: 0,
+            read_only: true,
+        }
+    }
+
+    /// Namen aller gespeicherten Profile, alphabetisch.
+    pub fn list() -> Vec<String> {
+        let Ok(entries) = fs::read_dir(data_dir().join("profiles")) else {
+            return Vec::new();
+        };
+
+        let mut names: Vec<String> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|extension| extension == "json"))
+            .filter_map(|path| path.file_stem().and_then(|stem| stem.to_str()).map(str::to_string))
+            .collect();
+        names.sort();
+        names
+    }
+
+    /// Merkt sich, ab welcher Antwort die neue Sitzung zaehlt.
+    pub fn begin_session(&mut self) {
+        self.session_start = self.data.history.len();
+    }
+
+    /// Schliesst die laufende Sitzung ab und haelt sie fest, falls etwas beantwortet wurde.
+    /// Gespeichert wird erst mit `save`.
+    pub fn finish_session(&mut self, seconds: f32) -> Summary {
+        let start = self.session_start.min(self.data.history.len());
+        let summary = Summary::new(
+            &self.data.history[start..],
+            seconds,
+            now(),
+            self.data.sessions.last().cloned(),
+        );
+
+        if summary.record.total > 0 {
+            self.data.sessions.push(summary.record.clone());
+        }
+        self.session_start = self.data.history.len();
+        summary
+    }
+
+    /// Haelt eine bewertete Runde fest. Gespeichert wird erst mit `save`.
+    pub fn record(&mut self, grade: &Grade) {
+        let timestamp = now();
+
+        self.data.confusion.record(&grade.expected, &grade.typed);
+        self.data.history.push(AnswerRecord {
+            timestamp,
+            expected: grade.expected.to_lowercase(),
+            typed: grade.typed.trim().to_lowercase(),
+            correct: grade.correct,
+            latency_ms: grade.latency.as_millis() as u64,
+            wpm: grade.signal.wpm,
+            pitch_hz: grade.signal.pitch_hz,
+            noise_level: grade.signal.noise_level,
+            replays: grade.replays,
+            credit: (grade.correct && grade.credit < 1.0).then_some(grade.credit),
+        });
+    }
+
+    pub fn add_practice_time(&mut self, seconds: f32) {
+        *self.data.practice_seconds.entry(day_key(today())).or_default() += seconds;
+    }
+
+    pub fn save(&self) {
+        let Some(path) = Self::path(&self.name).filter(|_| !self.read_only) else {
+            return;
+        };
+
+        if let Some(dir) = path.parent()
+            && let Err(err) = fs::create_dir_all(dir)
+        {
+            let error = tr!("error-unwritable", path = dir.display().to_string(), error = err.to_string());
+            eprintln!("{}", tr!("error", error = error));
+            return;
+        }
+
+        let result = serde_json::to_string_pretty(&self.data)
+            .map_err(|err| err.to_string())
+            .and_then(|json| fs::write(&path, json).map_err(|err| err.to_string()));
+
+        if let Err(err) = result {
+            let error = tr!("error-profile-unsaved", path = path.display().to_string(), error = err);
+            eprintln!("{}", tr!("error", error = error));
+        }
+    }
+
+    fn path(name: &str) -> Option<PathBuf> {
+        Self::is_valid_name(name).then(|| data_dir().join("profiles").join(format!("{}.json", name)))
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs() as i64)
+}
+
+/// Ablageort fuer Profile und Exporte.
+pub fn data_dir() -> PathBuf {
+    dirs::data_dir()
+        .unwrap_or_else(|| PathBuf::from("."))
+        .join("cw-listen-trainer")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn names_stay_inside_the_profile_dir() {
+        for name in ["default", "dd6ds", "morgens-2", "a.b"] {
+            assert!(Profile::is_valid_name(name), "{}", name);
+        }
+        for name in ["", "..", "../../x", "a/b", "a\\b", "x.."] {
+            assert!(!Profile::is_valid_name(name), "{}", name);
+            assert!(Profile::path(name).is_none(), "{}", name);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Sitzungen als Audiodatei zum Anhoeren unterwegs, mit Loesungsblatt daneben.
+
+use std::{fs, io, path::{Path, PathBuf}, time::Duration};
+
+use crate::flac;
+use crate::morse::{self, Speed};
+use crate::tr;
+use crate::training_mode::{classify, label, name_of, CharClass, TrainingMode};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum AudioFormat {
+    Wav,
+    Flac,
+    /// FLAC im Ogg-Container; einen Vorbis-Encoder gibt es nicht
+    Ogg,
+}
+
+impl AudioFormat {
+    /// Format anhand der Dateiendung.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension()?.to_str()?.to_lowercase().as_str() {
+            "wav" => Some(AudioFormat::Wav),
+            "flac" => Some(AudioFormat::Flac),
+            "ogg" | "oga" => Some(AudioFormat::Ogg),
+            _ => None,
+        }
+    }
+
+    pub fn encode(self, samples: &[f32]) -> Vec<u8> {
+        let pcm = || samples.iter().map(|sample| morse::quantize(*sample)).collect::<Vec<_>>();
+
+        match self {
+            AudioFormat::Wav => morse::wav_bytes(samples),
+            AudioFormat::Flac => flac::encode(&pcm()),
+            AudioFormat::Ogg => flac::encode_ogg(&pcm()),
+        }
+    }
+}
+
+/// Ein Eintrag der Aufnahme, z.B. eine Fuenfergruppe oder ein Stueck Text, als Woerter aus Zeichennamen.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Item {
+    pub words: Vec<Vec<String>>,
+}
+
+impl Item {
+    pub fn from_text(text: &str) -> Self {
+        Self {
+            words: text
+                .split_whitespace()
+                .map(|word| word.chars().map(|c| name_of(&c.to_string())).collect())
+                .collect(),
+        }
+    }
+
+    /// So steht der Eintrag im Loesungsblatt; Betriebszeichen in spitzen Klammern.
+    pub fn key(&self) -> String {
+        self.words
+            .iter()
+            .map(|word| {
+                word.iter()
+                    .map(|name| match classify(name) {
+                        CharClass::Prosign => format!("<{}>", label(name)),
+                        _ => label(name),
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+/// `count` Gruppen aus je `size` zufaelligen Zeichen der Auswahl.
+pub fn random_groups(mode: &mut TrainingMode, count: usize, size: usize) -> Vec<Item> {
+    (0..count)
+        .map(|_| Item {
+            words: vec![(0..size).filter_map(|_| mode.next_character()).collect()],
+        })
+        .filter(|item| item.words.iter().any(|word| !word.is_empty()))
+        .collect()
+}
+
+pub struct Recording {
+    pub items: Vec<Item>,
+    pub speed: Speed,
+    pub tone_hz: f32,
+    /// Pause nach jedem Eintrag, zum Mitschreiben
+    pub gap: Duration,
+}
+
+impl Recording {
+    pub fn render(&self) -> Vec<f32> {
+        let mut samples = Vec::new();
+        // Kurzer Vorlauf, damit der Anfang beim Einschalten nicht verloren geht
+        morse::append_silence(&mut samples, Duration::from_secs(1));
+
+        for item in &self.items {
+            samples.extend(morse::render_words(&item.words, self.speed, self.tone_hz));
+            morse::append_silence(&mut samples, self.gap);
+        }
+
+        samples
+    }
+
+    pub fn answer_key(&self) -> String {
+        let speed = match self.speed.farnsworth {
+            Some(effective) => tr!("record-speed-farnsworth", wpm = self.speed.wpm.to_string(), effective = effective.to_string()),
+            None => tr!("record-speed", wpm = self.speed.wpm.to_string()),
+        };
+
+        let mut key = format!("{}\n\n", speed);
+        for (index, item) in self.items.iter().enumerate() {
+            key.push_str(&format!("{:>4}. {}\n", index + 1, item.key()));
+        }
+        key
+    }
+
+    /// Schreibt die Aufnahme nach `path` und das Loesungsblatt mit Endung `.txt` daneben.
+    pub fn write(&self, path: &Path, format: AudioFormat) -> io::Result<PathBuf> {
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(path, format.encode(&self.render()))?;
+
+        let key_path = path.with_extension("txt");
+        fs::write(&key_path, self.answer_key())?;
+        Ok(key_path)
+    }
+}
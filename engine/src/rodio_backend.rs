@@ -0,0 +1,94 @@
+use std::{fs::File, io::BufReader, time::Duration};
+
+use crate::audio::{AudioBackend, AudioEvent};
+use crate::library::Sound;
+use rodio::{Decoder, OutputStream, OutputStreamHandle, Sink};
+
+/// Wiedergabe ueber das Standard-Ausgabegeraet des Systems.
+pub struct RodioBackend {
+    // Muss am Leben bleiben, solange etwas abgespielt werden soll
+    _stream: OutputStream,
+    handle: OutputStreamHandle,
+    sink: Option<Sink>,
+    // Das Ende der laufenden Wiedergabe ist noch nicht gemeldet
+    playing: bool,
+    error: Option<String>,
+    events: Vec<AudioEvent>,
+}
+
+impl RodioBackend {
+    pub fn new() -> Result<Self, String> {
+        let (stream, handle) = OutputStream::try_default().map_err(|err| err.to_string())?;
+
+        Ok(Self {
+            _stream: stream,
+            handle,
+            sink: None,
+            playing: false,
+            error: None,
+            events: Vec::new(),
+        })
+    }
+
+    fn open(&self, sound: &Sound) -> Result<Sink, String> {
+        let file = File::open(&sound.path).map_err(|err| format!("{}: {}", sound.path.display(), err))?;
+        let source = Decoder::new(BufReader::new(file)).map_err(|err| format!("{}: {}", sound.path.display(), err))?;
+        let sink = Sink::try_new(&self.handle).map_err(|err| err.to_string())?;
+        sink.append(source);
+        Ok(sink)
+    }
+}
+
+impl AudioBackend for RodioBackend {
+    fn play(&mut self, sound: &Sound) {
+        self.stop();
+
+        match self.open(sound) {
+            Ok(sink) => {
+                self.sink = Some(sink);
+                self.playing = true;
+                self.error = None;
+                self.events.push(AudioEvent::Started);
+            }
+            Err(err) => self.error = Some(err),
+        }
+    }
+
+    fn stop(&mut self) {
+        self.playing = false;
+        if let Some(sink) = self.sink.take() {
+            sink.stop();
+        }
+    }
+
+    fn pause(&mut self) {
+        if let Some(sink) = &self.sink {
+            sink.pause();
+        }
+    }
+
+    fn resume(&mut self) {
+        if let Some(sink) = &self.sink {
+            sink.play();
+        }
+    }
+
+    fn is_playing(&self) -> bool {
+        self.sink.as_ref().is_some_and(|sink| !sink.empty())
+    }
+
+    fn advance(&mut self, _elapsed: Duration) {
+        if self.playing && !self.is_playing() {
+            self.playing = false;
+            self.events.push(AudioEvent::Finished);
+        }
+    }
+
+    fn take_events(&mut self) -> Vec<AudioEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    fn last_error(&self) -> Option<&str> {
+        self.error.as_deref()
+    }
+}
@@ -0,0 +1,926 @@
+use std::{collections::VecDeque, fmt, time::Duration};
+
+use crate::alignment::{align_windowed, AlignOp};
+use crate::grading::{is_correct, normalize};
+use crate::tr;
+use crate::library::{Signal, Sound, SoundLibrary};
+use crate::text::TextPractice;
+use crate::training_mode::{label, name_of, DrillMode, TrainingMode};
+
+// So viele gesendete Zeichen werden beim Mitschreiben auf einmal abgeglichen
+const ALIGN_WINDOW: usize = 256;
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PlayState {
+    Playing,
+    Pausing,
+    WaitingForAnswer,
+    ReadyToPlay,
+    Stopped,
+    RepeatPlaying,
+    RepeatPausing,
+}
+
+/// Alle Wartezeiten des Ablaufs.
+#[derive(Clone, Debug)]
+pub struct Timings {
+    /// Pause nach einer Runde, bevor das naechste Zeichen kommt
+    pub pause: Duration,
+    /// Pause zwischen den Wiederholungen nach einer falschen Antwort
+    pub repeat_pause: Duration,
+    /// Anzahl Wiederholungen nach einer falschen Antwort
+    pub repeats: u32,
+}
+
+impl Default for Timings {
+    fn default() -> Self {
+        Self {
+            pause: Duration::from_secs(2),
+            repeat_pause: Duration::from_millis(800),
+            repeats: 3,
+        }
+    }
+}
+
+/// Einstellungen fuer das Mitschreiben, bei dem die Zeichen ohne Warten auf Antworten kommen.
+#[derive(Clone, Debug)]
+pub struct CopyBehind {
+    /// So viele Zeichen darf die Eingabe am Ende hinter dem Gesendeten liegen
+    pub lag: usize,
+    /// Pause zwischen zwei Zeichen
+    pub gap: Duration,
+}
+
+impl Default for CopyBehind {
+    fn default() -> Self {
+        Self {
+            lag: 2,
+            gap: Duration::from_millis(500),
+        }
+    }
+}
+
+/// Wann eine Sitzung von selbst endet; was zuerst erreicht ist, gilt.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Limits {
+    /// Trainingszeit ohne Pausen
+    pub duration: Option<Duration>,
+    /// Anzahl gesendeter Zeichen bzw. Stuecke
+    pub items: Option<u32>,
+    /// Trefferquote (0.0 bis 1.0) ueber die letzten `TARGET_WINDOW` Antworten
+    pub target_accuracy: Option<f32>,
+}
+
+impl Limits {
+    /// Ueber so viele Antworten wird die Trefferquote fuer `target_accuracy` ermittelt.
+    pub const TARGET_WINDOW: usize = 20;
+}
+
+impl fmt::Display for Limits {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if let Some(duration) = self.duration {
+            parts.push(tr!("limit-minutes", minutes = format!("{:.0}", duration.as_secs_f32() / 60.0)));
+        }
+        if let Some(items) = self.items {
+            parts.push(tr!("limit-items", items = items));
+        }
+        if let Some(target) = self.target_accuracy {
+            parts.push(tr!("limit-target", percent = format!("{:.0}", target * 100.0)));
+        }
+
+        if parts.is_empty() {
+            write!(f, "{}", tr!("home-unlimited"))
+        } else {
+            write!(f, "{}", tr!("home-limits", limits = parts.join(&tr!("limit-or"))))
+        }
+    }
+}
+
+/// Eingaben an die Sitzung.
+#[derive(Clone, PartialEq, Debug)]
+pub enum SessionEvent {
+    Start,
+    Stop,
+    Pause,
+    Resume,
+    /// Die zuletzt angeforderte Wiedergabe laeuft tatsaechlich; ein folgendes Ende gilt dann sofort
+    PlaybackStarted,
+    /// Das Frontend hoert nichts mehr von der zuletzt gestarteten Wiedergabe
+    PlaybackFinished,
+    /// Der Sound liess sich nicht laden oder dekodieren
+    PlaybackFailed(String),
+    AnswerSubmitted(String),
+    /// Ein getipptes Zeichen beim Mitschreiben
+    Typed(char),
+    /// Letztes getipptes Zeichen beim Mitschreiben loeschen
+    Erase,
+    /// Aktuelles Zeichen noch einmal abspielen; kostet nur mit `set_replay_credit` etwas
+    Replay,
+    /// Aktuelles Zeichen ohne Bewertung ueberspringen
+    Skip,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct Grade {
+    pub expected: String,
+    pub typed: String,
+    pub correct: bool,
+    /// Zeit vom Ende der Wiedergabe bis zur Antwort
+    pub latency: Duration,
+    /// Wie oft das Zeichen vor der Antwort noch einmal angehoert wurde
+    pub replays: u32,
+    /// Wertung von 0.0 bis 1.0; nach einem Nochmal evtl. weniger als eine volle richtige Antwort
+    pub credit: f32,
+    /// Tempo, Tonhoehe und Rauschen des gehoerten Zeichens
+    pub signal: Signal,
+}
+
+/// Was das Frontend tun muss; abgeholt ueber `TrainingSession::take_effects`.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Effect {
+    /// Sound abspielen und dabei eine laufende Wiedergabe ersetzen
+    Play(Sound),
+    StopAudio,
+    PauseAudio,
+    ResumeAudio,
+    Graded(Grade),
+}
+
+/// Was dem Benutzer gerade angezeigt werden soll, unabhaengig von der Sprache.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum Prompt {
+    Ready,
+    Listening,
+    AwaitingAnswer,
+    Correct { expected: String },
+    Wrong { expected: String, typed: String, repeats: usize },
+    Skipped { expected: String },
+    Repeat { name: String, solution: bool, index: usize, total: usize },
+    Pause,
+    Stopped,
+    NothingSelected,
+    TimeUp,
+    ItemsDone,
+    TargetReached,
+    Streaming { sent: usize, typed: String },
+    StreamResult { sent: String, typed: String, correct: usize, total: usize, errors: usize },
+    /// Ein Stueck Text wurde ohne Abfrage gesendet
+    Sent { text: String },
+    /// Ein Zeichen konnte nicht abgespielt werden und wurde ausgelassen
+    PlaybackFailed { name: String, error: String },
+    CopyCheck,
+    TextFinished,
+    Failed { error: String },
+}
+
+impl fmt::Display for Prompt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Prompt::Ready => write!(f, "{}", tr!("prompt-ready")),
+            Prompt::Listening => write!(f, "{}", tr!("prompt-listening")),
+            Prompt::AwaitingAnswer => write!(f, "{}", tr!("prompt-awaiting-answer")),
+            Prompt::Correct { expected } => write!(f, "{}", expected),
+            Prompt::Wrong { expected, typed, repeats } => write!(
+                f,
+                "{}",
+                tr!("prompt-wrong", expected = expected.as_str(), typed = typed.as_str(), repeats = *repeats)
+            ),
+            Prompt::Skipped { expected } => write!(f, "{}", tr!("prompt-skipped", expected = expected.as_str())),
+            Prompt::Repeat { name, solution, index, total } => {
+                let id = if *solution { "prompt-repeat-solution" } else { "prompt-repeat-typed" };
+                write!(f, "{}", tr!(id, name = name.as_str(), index = *index, total = *total))
+            }
+            Prompt::Pause => write!(f, "{}", tr!("prompt-pause")),
+            Prompt::Stopped => write!(f, "{}", tr!("prompt-stopped")),
+            Prompt::NothingSelected => write!(f, "{}", tr!("prompt-nothing-selected")),
+            Prompt::TimeUp => write!(f, "{}", tr!("prompt-time-up")),
+            Prompt::ItemsDone => write!(f, "{}", tr!("prompt-items-done")),
+            Prompt::TargetReached => write!(f, "{}", tr!("prompt-target-reached")),
+            Prompt::Streaming { sent, typed } => {
+                write!(f, "{}", tr!("prompt-streaming", sent = *sent, typed = typed.as_str()))
+            }
+            Prompt::StreamResult { sent, typed, correct, total, errors } => write!(
+                f,
+                "{}",
+                tr!(
+                    "prompt-stream-result",
+                    sent = sent.as_str(),
+                    typed = typed.as_str(),
+                    correct = *correct,
+                    total = *total,
+                    errors = *errors,
+                )
+            ),
+            Prompt::Sent { text } => write!(f, "{}", text),
+            Prompt::PlaybackFailed { name, error } => write!(
+                f,
+                "{}",
+                tr!("prompt-playback-failed", name = label(name), error = error.as_str())
+            ),
+            Prompt::CopyCheck => write!(f, "{}", tr!("prompt-copy-check")),
+            Prompt::TextFinished => write!(f, "{}", tr!("prompt-text-finished")),
+            Prompt::Failed { error } => write!(f, "{}", tr!("error", error = error.as_str())),
+        }
+    }
+}
+
+/// Der komplette Trainingsablauf ohne Bezug zu Fenster oder Audio-Ausgabe.
+pub struct TrainingSession {
+    library: SoundLibrary,
+    mode: TrainingMode,
+    timings: Timings,
+    state: PlayState,
+    paused: bool,
+    // Zeit seit Eintritt in den aktuellen Zustand
+    elapsed: Duration,
+    current: Option<Sound>,
+    // Das Frontend hat den Beginn der laufenden Wiedergabe gemeldet
+    playback_started: bool,
+    // Fehler, der waehrend der Pause gemeldet wurde und erst danach gilt
+    deferred_failure: Option<String>,
+    // Wie oft das aktuelle Zeichen auf Wunsch nochmal gespielt wurde
+    replays: u32,
+    replay_credit: f32,
+    repeat_playlist: Vec<Sound>,
+    repeat_index: usize,
+    correct_count: u32,
+    wrong_count: u32,
+    // Trainingszeit seit dem letzten Start, ohne Pausen
+    training_time: Duration,
+    limits: Limits,
+    // Gesendete Runden und die Wertung der letzten Antworten seit dem letzten Start
+    rounds: u32,
+    recent: VecDeque<f32>,
+    copy_behind: CopyBehind,
+    // Beim Mitschreiben: gesendete Zeichen mit Ende der Wiedergabe und getippte mit Zeitpunkt
+    stream_sent: Vec<(String, Duration)>,
+    stream_typed: Vec<(char, Duration)>,
+    // Statt einzelner Zeichen wird dieser Text gesendet
+    text: Option<TextPractice>,
+    prompt: Prompt,
+    effects: VecDeque<Effect>,
+}
+
+impl TrainingSession {
+    pub fn new(library: SoundLibrary, timings: Timings) -> Self {
+        let mode = TrainingMode::new(library.names());
+
+        Self {
+            library,
+            mode,
+            timings,
+            state: PlayState::ReadyToPlay,
+            paused: false,
+            elapsed: Duration::ZERO,
+            current: None,
+            playback_started: false,
+            deferred_failure: None,
+            replays: 0,
+            replay_credit: 1.0,
+            repeat_playlist: Vec::new(),
+            repeat_index: 0,
+            correct_count: 0,
+            wrong_count: 0,
+            training_time: Duration::ZERO,
+            limits: Limits::default(),
+            rounds: 0,
+            recent: VecDeque::new(),
+            copy_behind: CopyBehind::default(),
+            stream_sent: Vec::new(),
+            stream_typed: Vec::new(),
+            text: None,
+            prompt: Prompt::Ready,
+            effects: VecDeque::new(),
+        }
+    }
+
+    pub fn state(&self) -> PlayState {
+        self.state
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn prompt(&self) -> &Prompt {
+        &self.prompt
+    }
+
+    pub fn correct_count(&self) -> u32 {
+        self.correct_count
+    }
+
+    pub fn wrong_count(&self) -> u32 {
+        self.wrong_count
+    }
+
+    pub fn current(&self) -> Option<&Sound> {
+        self.current.as_ref()
+    }
+
+    pub fn library(&self) -> &SoundLibrary {
+        &self.library
+    }
+
+    pub fn mode(&self) -> &TrainingMode {
+        &self.mode
+    }
+
+    pub fn mode_mut(&mut self) -> &mut TrainingMode {
+        &mut self.mode
+    }
+
+    pub fn timings(&self) -> &Timings {
+        &self.timings
+    }
+
+    /// Neue Zeiten gelten ab dem naechsten Zustandswechsel bzw. der naechsten falschen Antwort.
+    pub fn set_timings(&mut self, timings: Timings) {
+        self.timings = timings;
+    }
+
+    /// Ist ein Limit erreicht, wird keine neue Runde mehr begonnen.
+    pub fn set_limits(&mut self, limits: Limits) {
+        self.limits = limits;
+    }
+
+    /// Wertung einer richtigen Antwort nach einem Nochmal, 1.0 heisst ohne Abzug.
+    pub fn set_replay_credit(&mut self, credit: f32) {
+        self.replay_credit = credit.clamp(0.0, 1.0);
+    }
+
+    pub fn replay_credit(&self) -> f32 {
+        self.replay_credit
+    }
+
+    pub fn limits(&self) -> &Limits {
+        &self.limits
+    }
+
+    /// Gesendete Zeichen bzw. Stuecke seit dem letzten Start.
+    pub fn rounds(&self) -> u32 {
+        self.rounds
+    }
+
+    pub fn set_copy_behind(&mut self, copy_behind: CopyBehind) {
+        self.copy_behind = copy_behind;
+    }
+
+    pub fn copy_behind(&self) -> &CopyBehind {
+        &self.copy_behind
+    }
+
+    /// Mitschreiben: Zeichen kommen fortlaufend, getippt wird ueber `SessionEvent::Typed`.
+    pub fn is_streaming(&self) -> bool {
+        self.mode.drill == DrillMode::CopyBehind
+    }
+
+    /// Sendet den Text stueckweise statt zufaelliger Zeichen; `None` kehrt zu den Zeichen zurueck.
+    pub fn set_text(&mut self, text: Option<TextPractice>) {
+        self.text = text;
+    }
+
+    pub fn text(&self) -> Option<&TextPractice> {
+        self.text.as_ref()
+    }
+
+    pub fn training_time(&self) -> Duration {
+        self.training_time
+    }
+
+    /// Holt alle seit dem letzten Aufruf angefallenen Effekte ab.
+    pub fn take_effects(&mut self) -> Vec<Effect> {
+        self.effects.drain(..).collect()
+    }
+
+    pub fn handle(&mut self, event: SessionEvent) {
+        match event {
+            SessionEvent::Start => {
+                if self.state == PlayState::Stopped {
+                    self.enter(PlayState::ReadyToPlay);
+                    self.paused = false;
+                    self.training_time = Duration::ZERO;
+                    self.rounds = 0;
+                    self.recent.clear();
+                    self.correct_count = 0;
+                    self.wrong_count = 0;
+                    self.stream_sent.clear();
+                    self.stream_typed.clear();
+                }
+            }
+            SessionEvent::Stop => {
+                self.enter(PlayState::Stopped);
+                self.paused = false;
+                self.effects.push_back(Effect::StopAudio);
+                self.prompt = Prompt::Stopped;
+                self.finish_stream();
+            }
+            // Waehrend der Pause steht alles: keine Zeit, keine Antworten, kein Zustandswechsel ausser Stop
+            SessionEvent::Pause => {
+                if self.state != PlayState::Stopped && !self.paused {
+                    self.paused = true;
+                    self.effects.push_back(Effect::PauseAudio);
+                }
+            }
+            SessionEvent::Resume => {
+                if self.paused {
+                    self.paused = false;
+                    self.effects.push_back(Effect::ResumeAudio);
+
+                    if let Some(error) = self.deferred_failure.take() {
+                        self.playback_failed(error);
+                    }
+                }
+            }
+            SessionEvent::PlaybackStarted => {
+                // Auch waehrend der Pause: ein angehaltener Sound kann schon begonnen haben
+                if matches!(self.state, PlayState::Playing | PlayState::RepeatPlaying) {
+                    self.playback_started = true;
+                }
+            }
+            SessionEvent::PlaybackFinished => self.playback_finished(),
+            SessionEvent::PlaybackFailed(error) => self.playback_failed(error),
+            SessionEvent::AnswerSubmitted(answer) => self.submit_answer(&answer),
+            SessionEvent::Typed(character) => {
+                if self.accepts_typing() {
+                    self.stream_typed.extend(character.to_lowercase().map(|c| (c, self.training_time)));
+                    self.update_stream_prompt();
+                }
+            }
+            SessionEvent::Erase => {
+                if self.accepts_typing() {
+                    self.stream_typed.pop();
+                    self.update_stream_prompt();
+                }
+            }
+            SessionEvent::Replay => self.replay(),
+            SessionEvent::Skip => self.skip(),
+        }
+    }
+
+    /// Laesst die Zeit fortschreiten; alle Timer haengen nur hiervon ab.
+    pub fn tick(&mut self, delta: Duration) {
+        if self.state == PlayState::Stopped || self.paused {
+            return;
+        }
+
+        self.elapsed += delta;
+        self.training_time += delta;
+
+        match self.state {
+            PlayState::ReadyToPlay => self.start_round(),
+            PlayState::Pausing if self.is_streaming() => {
+                if self.elapsed >= self.copy_behind.gap {
+                    self.enter(PlayState::ReadyToPlay);
+                }
+            }
+            PlayState::Pausing => {
+                // Beim Text bleibt das letzte Stueck bzw. der Vergleich stehen, ein Fehler ebenso
+                if self.text.is_none()
+                    && !matches!(self.prompt, Prompt::PlaybackFailed { .. })
+                    && self.elapsed.as_secs_f32() >= self.timings.pause.as_secs_f32() * 0.3 {
+                    self.prompt = Prompt::Pause;
+                }
+
+                if self.elapsed >= self.timings.pause {
+                    self.enter(PlayState::ReadyToPlay);
+                }
+            }
+            PlayState::RepeatPausing => {
+                if self.elapsed >= self.timings.repeat_pause {
+                    self.next_repeat();
+                }
+            }
+            PlayState::Playing
+            | PlayState::RepeatPlaying
+            | PlayState::WaitingForAnswer
+            | PlayState::Stopped => {}
+        }
+    }
+
+    fn enter(&mut self, state: PlayState) {
+        self.state = state;
+        self.elapsed = Duration::ZERO;
+        self.playback_started = false;
+        self.deferred_failure = None;
+    }
+
+    fn start_round(&mut self) {
+        if let Some(reached) = self.reached_limit() {
+            self.enter(PlayState::Stopped);
+            self.finish_stream();
+            self.prompt = reached;
+            return;
+        }
+
+        let sound = match self.text.as_mut() {
+            Some(text) => match text.next_sound() {
+                Some(Ok(sound)) => Some(sound),
+                Some(Err(err)) => {
+                    self.enter(PlayState::Stopped);
+                    self.prompt = Prompt::Failed { error: err.to_string() };
+                    self.finish_stream();
+                    return;
+                }
+                None => {
+                    self.enter(PlayState::Stopped);
+                    self.prompt = Prompt::TextFinished;
+                    self.finish_stream();
+                    return;
+                }
+            },
+            None => self
+                .mode
+                .next_character()
+                .and_then(|name| self.library.find(&name).cloned()),
+        };
+
+        let Some(sound) = sound else {
+            self.enter(PlayState::Stopped);
+            self.prompt = Prompt::NothingSelected;
+            return;
+        };
+
+        self.effects.push_back(Effect::Play(sound.clone()));
+        self.rounds += 1;
+        self.prompt = Prompt::Listening;
+        if self.is_streaming() {
+            self.stream_sent.push((sound.name.clone(), self.training_time));
+            self.update_stream_prompt();
+        }
+        self.current = Some(sound);
+        self.replays = 0;
+        self.enter(PlayState::Playing);
+    }
+
+    fn reached_limit(&self) -> Option<Prompt> {
+        let limits = &self.limits;
+
+        if limits.duration.is_some_and(|limit| self.training_time >= limit) {
+            return Some(Prompt::TimeUp);
+        }
+        if limits.items.is_some_and(|items| self.rounds >= items) {
+            return Some(Prompt::ItemsDone);
+        }
+        // Erst wenn genug Antworten vorliegen, damit ein frueher Glueckstreffer nicht reicht
+        let accuracy = self.recent.iter().sum::<f32>() / self.recent.len().max(1) as f32;
+        if limits
+            .target_accuracy
+            .is_some_and(|target| self.recent.len() >= Limits::TARGET_WINDOW && accuracy >= target)
+        {
+            return Some(Prompt::TargetReached);
+        }
+        None
+    }
+
+    fn note_result(&mut self, credit: f32) {
+        self.recent.push_back(credit);
+        if self.recent.len() > Limits::TARGET_WINDOW {
+            self.recent.pop_front();
+        }
+    }
+
+    fn playback_finished(&mut self) {
+        // Ohne Meldung zum Beginn laedt der Sound noch; sein Ende kann noch nicht gekommen sein
+        if self.paused || !self.playback_started {
+            return;
+        }
+
+        match self.state {
+            PlayState::Playing if self.is_streaming() => {
+                if let Some((_, finished)) = self.stream_sent.last_mut() {
+                    *finished = self.training_time;
+                }
+                self.effects.push_back(Effect::StopAudio);
+                self.enter(PlayState::Pausing);
+            }
+            PlayState::Playing if self.text.as_ref().is_some_and(|text| !text.copy_check) => {
+                self.effects.push_back(Effect::StopAudio);
+                self.prompt = Prompt::Sent {
+                    text: self.current.as_ref().map(|sound| sound.name.clone()).unwrap_or_default(),
+                };
+                self.enter(PlayState::Pausing);
+            }
+            PlayState::Playing => {
+                self.effects.push_back(Effect::StopAudio);
+                self.prompt = if self.text.is_some() { Prompt::CopyCheck } else { Prompt::AwaitingAnswer };
+                self.enter(PlayState::WaitingForAnswer);
+            }
+            PlayState::RepeatPlaying => {
+                self.effects.push_back(Effect::StopAudio);
+                self.enter(PlayState::RepeatPausing);
+            }
+            _ => {}
+        }
+    }
+
+    /// Ein Zeichen, das nicht abgespielt werden kann, wird angezeigt und ohne Bewertung uebersprungen.
+    fn playback_failed(&mut self, error: String) {
+        if !matches!(self.state, PlayState::Playing | PlayState::RepeatPlaying) {
+            return;
+        }
+        if self.paused {
+            self.deferred_failure = Some(error);
+            return;
+        }
+
+        let name = self.current.as_ref().map(|sound| sound.name.clone()).unwrap_or_default();
+        if self.is_streaming() {
+            self.stream_sent.pop();
+        }
+
+        self.effects.push_back(Effect::StopAudio);
+        self.prompt = Prompt::PlaybackFailed { name, error };
+        self.enter(PlayState::Pausing);
+    }
+
+    fn submit_answer(&mut self, answer: &str) {
+        if self.paused || self.state != PlayState::WaitingForAnswer || answer.is_empty() {
+            return;
+        }
+        let Some(current) = self.current.clone() else {
+            return;
+        };
+
+        if self.text.is_some() {
+            // Ein Stueck Text wird Zeichen fuer Zeichen verglichen und nicht wiederholt
+            let sent: Vec<(char, Duration)> = current.name.chars().map(|c| (c, Duration::ZERO)).collect();
+            let typed: Vec<(char, Duration)> = answer.to_lowercase().chars().map(|c| (c, self.elapsed)).collect();
+            self.prompt = self.compare(&sent, &typed);
+            self.enter(PlayState::Pausing);
+            return;
+        }
+
+        let correct = is_correct(&current.name, answer);
+        let credit = match (correct, self.replays) {
+            (false, _) => 0.0,
+            (true, 0) => 1.0,
+            (true, _) => self.replay_credit,
+        };
+        self.note_result(credit);
+
+        self.effects.push_back(Effect::Graded(Grade {
+            expected: current.name.clone(),
+            typed: normalize(answer),
+            correct,
+            latency: self.elapsed,
+            replays: self.replays,
+            credit,
+            signal: current.signal,
+        }));
+
+        if correct {
+            self.correct_count += 1;
+            self.prompt = Prompt::Correct { expected: current.name };
+            self.enter(PlayState::Pausing);
+        } else {
+            self.wrong_count += 1;
+            self.repeat_playlist = self.repeat_playlist(&current, &normalize(answer));
+            self.repeat_index = 0;
+            self.prompt = Prompt::Wrong {
+                expected: current.name,
+                typed: answer.to_string(),
+                repeats: self.repeat_playlist.len(),
+            };
+            self.enter(PlayState::RepeatPausing);
+        }
+    }
+
+    /// Beim Mitschreiben laeuft der Text weiter, dort gibt es kein aktuelles Zeichen zum Wiederholen.
+    fn replay(&mut self) {
+        if self.paused || self.is_streaming() || !matches!(self.state, PlayState::Playing | PlayState::WaitingForAnswer) {
+            return;
+        }
+        let Some(current) = self.current.clone() else {
+            return;
+        };
+
+        self.effects.push_back(Effect::Play(current));
+        self.replays += 1;
+        self.prompt = Prompt::Listening;
+        self.enter(PlayState::Playing);
+    }
+
+    fn skip(&mut self) {
+        if self.paused
+            || self.is_streaming()
+            || matches!(self.state, PlayState::Stopped | PlayState::ReadyToPlay | PlayState::Pausing)
+        {
+            return;
+        }
+
+        self.effects.push_back(Effect::StopAudio);
+        self.prompt = Prompt::Skipped {
+            expected: self.current.as_ref().map(|sound| sound.name.clone()).unwrap_or_default(),
+        };
+        self.enter(PlayState::Pausing);
+    }
+
+    /// Was nach einer falschen Antwort wiederholt wird: im Verwechsler-Training
+    /// Loesung und getipptes Zeichen abwechselnd, sonst nur die Loesung.
+    fn repeat_playlist(&self, correct: &Sound, typed: &str) -> Vec<Sound> {
+        let repeats = self.timings.repeats as usize;
+
+        match self.library.find(typed) {
+            Some(other) if self.mode.drill == DrillMode::Confusables => (0..repeats)
+                .map(|index| if index % 2 == 0 { correct.clone() } else { other.clone() })
+                .collect(),
+            _ => vec![correct.clone(); repeats],
+        }
+    }
+
+    fn next_repeat(&mut self) {
+        let total = self.repeat_playlist.len();
+
+        if self.repeat_index >= total {
+            self.enter(PlayState::Pausing);
+            return;
+        }
+
+        let sound = self.repeat_playlist[self.repeat_index].clone();
+        self.repeat_index += 1;
+
+        self.prompt = Prompt::Repeat {
+            solution: self.current.as_ref() == Some(&sound),
+            name: sound.name.clone(),
+            index: self.repeat_index,
+            total,
+        };
+        self.effects.push_back(Effect::Play(sound));
+        self.enter(PlayState::RepeatPlaying);
+    }
+
+    fn accepts_typing(&self) -> bool {
+        self.is_streaming() && self.state != PlayState::Stopped && !self.paused
+    }
+
+    fn update_stream_prompt(&mut self) {
+        self.prompt = Prompt::Streaming {
+            sent: self.stream_sent.len(),
+            typed: self.stream_typed.iter().map(|(c, _)| *c).collect(),
+        };
+    }
+
+    /// Gleicht beim Mitschreiben die getippten Zeichen mit den gesendeten ab und bewertet sie.
+    fn finish_stream(&mut self) {
+        if !self.is_streaming() || self.stream_sent.is_empty() {
+            return;
+        }
+
+        // Verglichen wird Zeichen fuer Zeichen so, wie sie getippt werden, z.B. "?" statt "fragezeichen"
+        let sent: Vec<(char, Duration)> = self
+            .stream_sent
+            .iter()
+            .flat_map(|(name, finished)| label(name).to_lowercase().chars().map(|c| (c, *finished)).collect::<Vec<_>>())
+            .filter(|(c, _)| !c.is_whitespace())
+            .collect();
+        let typed_count = self.stream_typed.iter().filter(|(c, _)| !c.is_whitespace()).count();
+
+        // Die letzten Zeichen duerfen noch fehlen, solange sie innerhalb der erlaubten Verzoegerung liegen
+        let pending = self.copy_behind.lag.min(sent.len().saturating_sub(typed_count));
+        let sent = &sent[..sent.len() - pending];
+
+        let typed = self.stream_typed.clone();
+        self.prompt = self.compare(sent, &typed);
+    }
+
+    /// Bewertet getippte gegen gesendete Zeichen nach bester Zuordnung; Leerzeichen zaehlen nicht.
+    /// Die Zeiten sind Ende der Wiedergabe bzw. Zeitpunkt der Eingabe.
+    fn compare(&mut self, sent: &[(char, Duration)], typed: &[(char, Duration)]) -> Prompt {
+        let sent_text: String = sent.iter().map(|(c, _)| *c).collect();
+        let typed_text: String = typed.iter().map(|(c, _)| *c).collect();
+        // Alle Zeichen eines Stroms kommen aus derselben Quelle
+        let signal = self.current.as_ref().map(|sound| sound.signal).unwrap_or_default();
+
+        let sent: Vec<(char, Duration)> = sent.iter().copied().filter(|(c, _)| !c.is_whitespace()).collect();
+        let typed: Vec<(char, Duration)> = typed.iter().copied().filter(|(c, _)| !c.is_whitespace()).collect();
+
+        let sent_chars: Vec<char> = sent.iter().map(|(c, _)| *c).collect();
+        let typed_chars: Vec<char> = typed.iter().map(|(c, _)| *c).collect();
+        let alignment = align_windowed(&sent_chars, &typed_chars, ALIGN_WINDOW);
+
+        for op in &alignment.ops {
+            let (sent_index, typed_index, correct) = match *op {
+                AlignOp::Match { sent, typed } => (sent, typed, true),
+                AlignOp::Substitute { sent, typed } => (sent, typed, false),
+                AlignOp::Missed { .. } | AlignOp::Extra { .. } => continue,
+            };
+            let (expected, finished) = sent[sent_index];
+            let (typed, typed_at) = typed[typed_index];
+
+            self.note_result(if correct { 1.0 } else { 0.0 });
+            self.effects.push_back(Effect::Graded(Grade {
+                expected: name_of(&expected.to_string()),
+                typed: name_of(&typed.to_string()),
+                correct,
+                latency: typed_at.saturating_sub(finished),
+                replays: 0,
+                credit: if correct { 1.0 } else { 0.0 },
+                signal,
+            }));
+        }
+
+        let correct = alignment.correct();
+        self.correct_count += correct as u32;
+        self.wrong_count += (sent.len() - correct) as u32;
+        Prompt::StreamResult {
+            sent: sent_text,
+            typed: typed_text.trim().to_string(),
+            correct,
+            total: sent.len(),
+            errors: alignment.distance,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::morse::{Speed, DEFAULT_TONE_HZ, DEFAULT_WPM};
+    use crate::test_util;
+
+    fn session() -> TrainingSession {
+        TrainingSession::new(test_util::library(&["a", "b"]), Timings::default())
+    }
+
+    #[test]
+    fn slow_sound_is_not_graded_early() {
+        let mut session = session();
+        session.tick(Duration::from_millis(10));
+        assert_eq!(session.state(), PlayState::Playing);
+        let expected = session.current().map(|sound| sound.name.clone()).unwrap();
+
+        // Der Sound laedt lange; ein Ende ohne Beginn und eine fruehe Antwort zaehlen nicht
+        session.tick(Duration::from_secs(5));
+        session.handle(SessionEvent::PlaybackFinished);
+        session.handle(SessionEvent::AnswerSubmitted(expected.clone()));
+        assert_eq!(session.state(), PlayState::Playing);
+        assert_eq!(session.correct_count(), 0);
+
+        session.handle(SessionEvent::PlaybackStarted);
+        session.tick(Duration::from_millis(300));
+        session.handle(SessionEvent::PlaybackFinished);
+        assert_eq!(session.state(), PlayState::WaitingForAnswer);
+
+        session.handle(SessionEvent::AnswerSubmitted(expected));
+        assert_eq!(session.correct_count(), 1);
+    }
+
+    #[test]
+    fn replay_waits_for_its_own_start() {
+        let mut session = session();
+        session.tick(Duration::from_millis(10));
+        session.handle(SessionEvent::PlaybackStarted);
+        session.handle(SessionEvent::PlaybackFinished);
+        assert_eq!(session.state(), PlayState::WaitingForAnswer);
+
+        session.handle(SessionEvent::Replay);
+        session.handle(SessionEvent::PlaybackFinished);
+        assert_eq!(session.state(), PlayState::Playing);
+    }
+
+    #[test]
+    fn copy_check_accepts_punctuation() {
+        let speed = Speed {
+            wpm: DEFAULT_WPM,
+            farnsworth: None,
+        };
+        let mut session = session();
+        session.set_text(Some(TextPractice::new(vec!["qrl?".to_string()], speed, DEFAULT_TONE_HZ, true)));
+        session.tick(Duration::from_millis(10));
+        session.handle(SessionEvent::PlaybackStarted);
+        session.handle(SessionEvent::PlaybackFinished);
+        assert_eq!(session.prompt(), &Prompt::CopyCheck);
+
+        session.handle(SessionEvent::AnswerSubmitted("QRL?".to_string()));
+        assert!(matches!(session.prompt(), Prompt::StreamResult { correct: 4, total: 4, .. }));
+        assert_eq!(session.wrong_count(), 0);
+    }
+
+    #[test]
+    fn grades_carry_the_signal() {
+        let speed = Speed {
+            wpm: 25.0,
+            farnsworth: None,
+        };
+        let signal = Signal::synthesized(speed, 700.0);
+        let sound = Sound { signal, ..Sound::new("a.wav".into(), "a") };
+        let mut session = TrainingSession::new(SoundLibrary::from_sounds(vec![sound]), Timings::default());
+        session.tick(Duration::from_millis(10));
+        session.handle(SessionEvent::PlaybackStarted);
+        session.handle(SessionEvent::PlaybackFinished);
+        session.take_effects();
+
+        session.handle(SessionEvent::AnswerSubmitted("a".to_string()));
+        let grade = session
+            .take_effects()
+            .into_iter()
+            .find_map(|effect| match effect {
+                Effect::Graded(grade) => Some(grade),
+                _ => None,
+            })
+            .expect("Bewertung");
+        assert_eq!(grade.signal, signal);
+    }
+}
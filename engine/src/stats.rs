@@ -0,0 +1,246 @@
+use std::collections::{BTreeMap, BTreeSet};
+use chrono::{Days, Local, NaiveDate, TimeZone};
+use serde::{Deserialize, Serialize};
+
+/// Eine einzelne beantwortete Runde.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct AnswerRecord {
+    /// Unix-Zeit in Sekunden
+    pub timestamp: i64,
+    pub expected: String,
+    pub typed: String,
+    pub correct: bool,
+    pub latency_ms: u64,
+    // Nur bei synthetisch erzeugten Zeichen bekannt, nicht bei MP3-Dateien
+    #[serde(default)]
+    pub wpm: Option<f32>,
+    #[serde(default)]
+    pub pitch_hz: Option<f32>,
+    #[serde(default)]
+    pub noise_level: Option<f32>,
+    /// Wie oft das Zeichen vor der Antwort noch einmal angehoert wurde
+    #[serde(default)]
+    pub replays: u32,
+    /// Nur bei Teilpunkten nach einem Nochmal, sonst zaehlt `correct` voll
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub credit: Option<f32>,
+}
+
+impl AnswerRecord {
+    /// Wertung von 0.0 bis 1.0 fuer Trefferquoten.
+    pub fn score(&self) -> f32 {
+        match (self.correct, self.credit) {
+            (false, _) => 0.0,
+            (true, Some(credit)) => credit,
+            (true, None) => 1.0,
+        }
+    }
+
+    pub fn date(&self) -> NaiveDate {
+        Local
+            .timestamp_opt(self.timestamp, 0)
+            .single()
+            .map(|time| time.date_naive())
+            .unwrap_or_default()
+    }
+}
+
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+pub fn day_key(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+pub struct CharStats {
+    pub name: String,
+    pub total: u32,
+    pub correct: u32,
+    /// Summe der Wertungen, bei Teilpunkten nach einem Nochmal kleiner als `correct`
+    pub score: f32,
+    pub replays: u32,
+    pub median_latency_ms: u64,
+}
+
+impl CharStats {
+    pub fn accuracy(&self) -> f32 {
+        self.score / self.total.max(1) as f32
+    }
+}
+
+/// Die letzten `days` Tage bis heute, aeltester zuerst.
+fn last_days(days: u64) -> Vec<NaiveDate> {
+    let today = today();
+    (0..days)
+        .rev()
+        .filter_map(|offset| today.checked_sub_days(Days::new(offset)))
+        .collect()
+}
+
+pub fn daily_accuracy(history: &[AnswerRecord], days: u64) -> Vec<(NaiveDate, Option<f32>)> {
+    let mut per_day: BTreeMap<NaiveDate, (f32, u32)> = BTreeMap::new();
+    for record in history {
+        let entry = per_day.entry(record.date()).or_default();
+        entry.0 += record.score();
+        entry.1 += 1;
+    }
+
+    last_days(days)
+        .into_iter()
+        .map(|date| {
+            let accuracy = per_day
+                .get(&date)
+                .map(|(score, total)| score / *total as f32);
+            (date, accuracy)
+        })
+        .collect()
+}
+
+pub fn daily_minutes(practice_seconds: &BTreeMap<String, f32>, days: u64) -> Vec<(NaiveDate, f32)> {
+    last_days(days)
+        .into_iter()
+        .map(|date| {
+            let seconds = practice_seconds.get(&day_key(date)).copied().unwrap_or(0.0);
+            (date, seconds / 60.0)
+        })
+        .collect()
+}
+
+/// Trefferquote und Median der Reaktionszeit pro Zeichen, schwaechste zuerst.
+pub fn per_character(history: &[AnswerRecord]) -> Vec<CharStats> {
+    let mut grouped: BTreeMap<&str, Vec<&AnswerRecord>> = BTreeMap::new();
+    for record in history {
+        grouped.entry(record.expected.as_str()).or_default().push(record);
+    }
+
+    let mut stats: Vec<CharStats> = grouped
+        .into_iter()
+        .map(|(name, records)| {
+            let mut latencies: Vec<u64> = records.iter().map(|record| record.latency_ms).collect();
+            latencies.sort_unstable();
+
+            CharStats {
+                name: name.to_string(),
+                total: records.len() as u32,
+                correct: records.iter().filter(|record| record.correct).count() as u32,
+                score: records.iter().map(|record| record.score()).sum(),
+                replays: records.iter().map(|record| record.replays).sum(),
+                median_latency_ms: latencies[latencies.len() / 2],
+            }
+        })
+        .collect();
+
+    stats.sort_by(|a, b| a.accuracy().total_cmp(&b.accuracy()));
+    stats
+}
+
+/// (aktuelle, laengste) Serie von Tagen in Folge mit Training.
+pub fn streaks(history: &[AnswerRecord], practice_seconds: &BTreeMap<String, f32>) -> (u32, u32) {
+    let mut days: BTreeSet<NaiveDate> = history.iter().map(AnswerRecord::date).collect();
+    days.extend(
+        practice_seconds
+            .keys()
+            .filter_map(|key| NaiveDate::parse_from_str(key, "%Y-%m-%d").ok()),
+    );
+
+    let mut best = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for day in &days {
+        run = match previous {
+            Some(prev) if prev.succ_opt() == Some(*day) => run + 1,
+            _ => 1,
+        };
+        best = best.max(run);
+        previous = Some(*day);
+    }
+
+    // Die aktuelle Serie zaehlt nur, wenn heute oder gestern trainiert wurde
+    let today = today();
+    let current = match previous {
+        Some(last) if last == today || last.succ_opt() == Some(today) => run,
+        _ => 0,
+    };
+
+    (current, best)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(expected: &str, correct: bool, latency_ms: u64) -> AnswerRecord {
+        AnswerRecord {
+            timestamp: Local::now().timestamp(),
+            expected: expected.to_string(),
+            typed: if correct { expected.to_string() } else { "e".to_string() },
+            correct,
+            latency_ms,
+            wpm: None,
+            pitch_hz: None,
+            noise_level: None,
+            replays: 0,
+            credit: None,
+        }
+    }
+
+    #[test]
+    fn characters_are_sorted_weakest_first() {
+        let history = [
+            answer("a", true, 300),
+            answer("a", true, 500),
+            answer("b", false, 900),
+            answer("b", true, 1500),
+            answer("b", true, 1100),
+            answer("c", false, 400),
+        ];
+
+        let stats = per_character(&history);
+        let names: Vec<&str> = stats.iter().map(|stats| stats.name.as_str()).collect();
+        assert_eq!(names, ["c", "b", "a"]);
+
+        let b = &stats[1];
+        assert_eq!((b.total, b.correct), (3, 2));
+        assert!((b.accuracy() - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(b.median_latency_ms, 1100);
+        assert_eq!(stats[2].median_latency_ms, 500);
+    }
+
+    #[test]
+    fn replays_give_partial_credit() {
+        let mut replayed = answer("a", true, 300);
+        replayed.replays = 2;
+        replayed.credit = Some(0.5);
+        let history = [replayed, answer("a", true, 300), answer("a", false, 300)];
+
+        assert_eq!(history[0].score(), 0.5);
+        assert_eq!(history[2].score(), 0.0);
+
+        let stats = &per_character(&history)[0];
+        assert_eq!(stats.correct, 2);
+        assert_eq!(stats.replays, 2);
+        assert!((stats.accuracy() - 0.5).abs() < 1e-6);
+
+        let days = daily_accuracy(&history, 2);
+        assert_eq!(days.len(), 2);
+        assert_eq!(days[0].1, None);
+        assert_eq!(days[1], (today(), Some(0.5)));
+    }
+
+    #[test]
+    fn streaks_count_days_in_a_row() {
+        let day = |offset: u64| day_key(today().checked_sub_days(Days::new(offset)).unwrap());
+        let practice: BTreeMap<String, f32> = [day(1), day(2), day(5), day(6), day(7), day(8)]
+            .into_iter()
+            .map(|key| (key, 60.0))
+            .collect();
+
+        assert_eq!(streaks(&[], &practice), (2, 4));
+        assert_eq!(streaks(&[answer("a", true, 300)], &practice), (3, 4));
+
+        let old: BTreeMap<String, f32> = [(day(3), 60.0)].into_iter().collect();
+        assert_eq!(streaks(&[], &old), (0, 1));
+        assert_eq!(daily_minutes(&practice, 2), [(today() - Days::new(1), 1.0), (today(), 0.0)]);
+    }
+}
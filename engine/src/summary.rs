@@ -0,0 +1,206 @@
+//! Rueckblick am Ende einer Sitzung und Vergleich mit der vorherigen.
+
+use std::fmt;
+use serde::{Deserialize, Serialize};
+
+use crate::stats::{per_character, AnswerRecord, CharStats};
+use crate::tr;
+use crate::training_mode::label;
+
+// So viele der schwaechsten Zeichen werden in der Zusammenfassung genannt
+const WEAKEST: usize = 3;
+
+/// Eckdaten einer abgeschlossenen Sitzung, damit die naechste damit verglichen werden kann.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct SessionRecord {
+    /// Unix-Zeit in Sekunden beim Ende
+    pub timestamp: i64,
+    pub seconds: f32,
+    pub total: u32,
+    pub correct: u32,
+    pub median_latency_ms: Option<u64>,
+    #[serde(default)]
+    pub replays: u32,
+    /// Summe der Wertungen; fehlt bei aelteren Sitzungen, dann zaehlt `correct`
+    #[serde(default)]
+    pub score: Option<f32>,
+}
+
+impl SessionRecord {
+    pub fn accuracy(&self) -> Option<f32> {
+        let score = self.score.unwrap_or(self.correct as f32);
+        (self.total > 0).then(|| score / self.total as f32)
+    }
+}
+
+/// Was am Ende einer Sitzung angezeigt wird.
+pub struct Summary {
+    pub record: SessionRecord,
+    /// Nur Zeichen mit Fehlern, schwaechste zuerst
+    pub weakest: Vec<CharStats>,
+    pub previous: Option<SessionRecord>,
+}
+
+impl Summary {
+    /// `answers` sind die Antworten dieser Sitzung, `previous` die letzte Sitzung davor.
+    pub fn new(answers: &[AnswerRecord], seconds: f32, timestamp: i64, previous: Option<SessionRecord>) -> Self {
+        let mut latencies: Vec<u64> = answers.iter().map(|answer| answer.latency_ms).collect();
+        latencies.sort_unstable();
+
+        let record = SessionRecord {
+            timestamp,
+            seconds,
+            total: answers.len() as u32,
+            correct: answers.iter().filter(|answer| answer.correct).count() as u32,
+            median_latency_ms: latencies.get(latencies.len() / 2).copied(),
+            replays: answers.iter().map(|answer| answer.replays).sum(),
+            score: Some(answers.iter().map(AnswerRecord::score).sum()),
+        };
+
+        let weakest = per_character(answers)
+            .into_iter()
+            .filter(|stats| stats.correct < stats.total)
+            .take(WEAKEST)
+            .collect();
+
+        Self { record, weakest, previous }
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let record = &self.record;
+        writeln!(f, "{}", tr!("summary-duration", minutes = format!("{:.1}", record.seconds / 60.0)))?;
+
+        let Some(accuracy) = record.accuracy() else {
+            return write!(f, "{}", tr!("summary-no-answers"));
+        };
+
+        writeln!(
+            f,
+            "{}",
+            tr!(
+                "summary-accuracy",
+                accuracy = format!("{:.0}", accuracy * 100.0),
+                correct = record.correct,
+                total = record.total,
+            )
+        )?;
+
+        if let Some(latency) = record.median_latency_ms {
+            writeln!(f, "{}", tr!("summary-latency", ms = latency))?;
+        }
+
+        if record.replays > 0 {
+            writeln!(f, "{}", tr!("summary-replays", replays = record.replays))?;
+        }
+
+        if !self.weakest.is_empty() {
+            let weakest: Vec<String> = self
+                .weakest
+                .iter()
+                .map(|stats| format!("{} ({}/{})", label(&stats.name), stats.correct, stats.total))
+                .collect();
+            writeln!(f, "{}", tr!("summary-weakest", characters = weakest.join(", ")))?;
+        }
+
+        match self.previous.as_ref().and_then(|previous| Some((previous, previous.accuracy()?))) {
+            Some((previous, previous_accuracy)) => {
+                let delta = (accuracy - previous_accuracy) * 100.0;
+                write!(
+                    f,
+                    "{}",
+                    tr!(
+                        "summary-previous",
+                        accuracy = format!("{:.0}", previous_accuracy * 100.0),
+                        delta = format!("{:+.0}", delta),
+                    )
+                )?;
+
+                if let (Some(now), Some(before)) = (record.median_latency_ms, previous.median_latency_ms) {
+                    write!(
+                        f,
+                        "\n{}",
+                        tr!(
+                            "summary-previous-latency",
+                            ms = before,
+                            delta = format!("{:+}", now as i64 - before as i64),
+                        )
+                    )?;
+                }
+                Ok(())
+            }
+            None => write!(f, "{}", tr!("summary-first")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn answer(expected: &str, correct: bool, latency_ms: u64) -> AnswerRecord {
+        AnswerRecord {
+            timestamp: 0,
+            expected: expected.to_string(),
+            typed: if correct { expected.to_string() } else { "e".to_string() },
+            correct,
+            latency_ms,
+            wpm: None,
+            pitch_hz: None,
+            noise_level: None,
+            replays: 0,
+            credit: None,
+        }
+    }
+
+    #[test]
+    fn summary_names_only_missed_characters() {
+        let answers = [
+            answer("a", true, 400),
+            answer("b", false, 1200),
+            answer("c", true, 300),
+            answer("c", false, 700),
+            answer("d", false, 900),
+            answer("e", false, 800),
+            answer("f", true, 200),
+        ];
+        let summary = Summary::new(&answers, 120.0, 1, None);
+
+        assert_eq!(summary.record.total, 7);
+        assert_eq!(summary.record.correct, 3);
+        assert_eq!(summary.record.median_latency_ms, Some(700));
+        // "c" ist nur halb falsch und faellt hinter die drei ganz falschen zurueck
+        let weakest: Vec<&str> = summary.weakest.iter().map(|stats| stats.name.as_str()).collect();
+        assert_eq!(weakest, ["b", "d", "e"]);
+    }
+
+    #[test]
+    fn empty_session_has_no_accuracy() {
+        let summary = Summary::new(&[], 30.0, 1, None);
+
+        assert_eq!(summary.record.accuracy(), None);
+        assert_eq!(summary.record.median_latency_ms, None);
+        assert!(summary.weakest.is_empty());
+    }
+
+    #[test]
+    fn older_sessions_count_correct_answers() {
+        let old = SessionRecord {
+            timestamp: 0,
+            seconds: 60.0,
+            total: 4,
+            correct: 3,
+            median_latency_ms: None,
+            replays: 0,
+            score: None,
+        };
+        assert_eq!(old.accuracy(), Some(0.75));
+
+        let mut replayed = answer("a", true, 400);
+        replayed.credit = Some(0.5);
+        let summary = Summary::new(&[replayed, answer("a", true, 400)], 60.0, 1, Some(old.clone()));
+        assert_eq!(summary.record.accuracy(), Some(0.75));
+        assert_eq!(summary.previous, Some(old));
+    }
+}
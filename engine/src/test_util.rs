@@ -0,0 +1,41 @@
+//! Hilfen fuer Tests in allen Frontends; nur mit `cfg(test)` oder dem Feature `test-util`.
+
+use std::{fs, path::{Path, PathBuf}, sync::atomic::{AtomicUsize, Ordering}};
+
+use crate::library::{Signal, Sound, SoundLibrary};
+use crate::morse::{self, Speed, DEFAULT_TONE_HZ, DEFAULT_WPM};
+
+/// Ein leeres Verzeichnis pro Aufruf, damit parallele Tests sich nicht gegenseitig die Dateien ueberschreiben.
+pub fn temp_dir() -> PathBuf {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+    let dir = std::env::temp_dir()
+        .join("cw-listen-trainer-tests")
+        .join(format!("{}-{}", std::process::id(), COUNT.fetch_add(1, Ordering::Relaxed)));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).expect("Testverzeichnis anlegen");
+    dir
+}
+
+/// Erzeugt das Zeichen `name` als WAV-Datei in `dir`.
+pub fn sound(dir: &Path, name: &str) -> Sound {
+    let speed = Speed {
+        wpm: DEFAULT_WPM,
+        farnsworth: None,
+    };
+    let code = morse::code(name).unwrap_or_else(|| panic!("kein Morsezeichen: {}", name));
+    let path = dir.join(format!("{}.wav", name));
+    fs::write(&path, morse::wav_bytes(&morse::render(code, speed, DEFAULT_TONE_HZ))).expect("Testsound schreiben");
+
+    Sound {
+        path,
+        name: name.to_string(),
+        signal: Signal::synthesized(speed, DEFAULT_TONE_HZ),
+    }
+}
+
+/// Die Zeichen `names` als abspielbare Bibliothek in einem eigenen Verzeichnis.
+pub fn library(names: &[&str]) -> SoundLibrary {
+    let dir = temp_dir();
+    SoundLibrary::from_sounds(names.iter().map(|name| sound(&dir, name)).collect())
+}
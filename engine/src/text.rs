@@ -0,0 +1,285 @@
+//! Beliebige Texte als Morse senden, z.B. Nachrichten, ein Buch oder einen Artikel aus der Funkzeitschrift.
+
+use std::{collections::VecDeque, fs, path::{Path, PathBuf}, sync::atomic::{AtomicUsize, Ordering}};
+
+use serde::{Deserialize, Serialize};
+
+use crate::library::{LibraryError, Signal, Sound};
+use crate::morse::{self, Speed};
+use crate::tr;
+use crate::training_mode::name_of;
+
+/// Was mit Zeichen passiert, die nicht in der aktiven Zeichenauswahl sind.
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum UnknownPolicy {
+    /// Weglassen
+    #[default]
+    Skip,
+    /// Durch ein aehnliches Zeichen ersetzen, z.B. "ä" durch "ae" oder "!" durch "."
+    Substitute,
+    /// Als Wort ausschreiben, z.B. "3" als "drei"; sonst wie `Substitute`
+    Spell,
+}
+
+impl UnknownPolicy {
+    pub const ALL: [UnknownPolicy; 3] = [UnknownPolicy::Skip, UnknownPolicy::Substitute, UnknownPolicy::Spell];
+
+    pub fn label(self) -> String {
+        tr!(match self {
+            UnknownPolicy::Skip => "policy-skip",
+            UnknownPolicy::Substitute => "policy-substitute",
+            UnknownPolicy::Spell => "policy-spell",
+        })
+    }
+}
+
+const SUBSTITUTES: [(char, &str); 27] = [
+    ('ä', "ae"),
+    ('ö', "oe"),
+    ('ü', "ue"),
+    ('ß', "ss"),
+    ('à', "a"),
+    ('á', "a"),
+    ('â', "a"),
+    ('è', "e"),
+    ('é', "e"),
+    ('ê', "e"),
+    ('ë', "e"),
+    ('ì', "i"),
+    ('í', "i"),
+    ('î', "i"),
+    ('ï', "i"),
+    ('ò', "o"),
+    ('ó', "o"),
+    ('ô', "o"),
+    ('ù', "u"),
+    ('ú', "u"),
+    ('û', "u"),
+    ('ç', "c"),
+    ('ñ', "n"),
+    ('!', "."),
+    (';', ","),
+    ('–', "-"),
+    ('—', "-"),
+];
+
+// Fluent-IDs der ausgeschriebenen Zeichen, jeweils mit "spell-" davor
+const SPELLED: [(char, &str); 26] = [
+    ('0', "0"),
+    ('1', "1"),
+    ('2', "2"),
+    ('3', "3"),
+    ('4', "4"),
+    ('5', "5"),
+    ('6', "6"),
+    ('7', "7"),
+    ('8', "8"),
+    ('9', "9"),
+    ('.', "period"),
+    (',', "comma"),
+    ('?', "question"),
+    ('!', "exclamation"),
+    ('=', "equals"),
+    ('/', "slash"),
+    ('-', "hyphen"),
+    (':', "colon"),
+    (';', "semicolon"),
+    ('+', "plus"),
+    ('&', "ampersand"),
+    ('@', "at"),
+    ('%', "percent"),
+    ('(', "parenthesis"),
+    (')', "parenthesis"),
+    ('"', "quote"),
+];
+
+/// Bringt einen Text in sendbare Form: nur Zeichen, fuer die `allows` gilt, kleingeschrieben
+/// und mit genau einem Leerzeichen zwischen den Woertern.
+pub fn prepare(text: &str, policy: UnknownPolicy, allows: impl Fn(&str) -> bool) -> String {
+    let sendable = |c: char| {
+        let name = name_of(&c.to_string());
+        morse::code(&name).is_some() && allows(&name)
+    };
+
+    let mut prepared = String::new();
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if c.is_whitespace() || sendable(c) {
+            prepared.push(c);
+            continue;
+        }
+
+        let spelled = SPELLED.iter().find(|(known, _)| *known == c);
+        let substitute = SUBSTITUTES.iter().find(|(known, _)| *known == c);
+
+        match (policy, spelled, substitute) {
+            (UnknownPolicy::Spell, Some((_, id)), _) => {
+                let word = tr!(&format!("spell-{}", id));
+                prepared.push(' ');
+                prepared.extend(word.to_lowercase().chars().filter(|c| sendable(*c)));
+                prepared.push(' ');
+            }
+            (UnknownPolicy::Substitute | UnknownPolicy::Spell, _, Some((_, replacement))) => {
+                prepared.extend(replacement.chars().filter(|c| sendable(*c)));
+            }
+            _ => {}
+        }
+    }
+
+    prepared.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Teilt einen vorbereiteten Text in Stuecke aus je `words` Woertern.
+pub fn chunks(text: &str, words: usize) -> Vec<String> {
+    text.split(' ')
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .chunks(words.max(1))
+        .map(|chunk| chunk.join(" "))
+        .collect()
+}
+
+// So viele Stuecke liegen gleichzeitig als Datei vor, damit eine laufende Wiedergabe nicht ueberschrieben wird
+const FILES: usize = 4;
+
+/// Ein Text, der Stueck fuer Stueck gesendet wird; der Ton wird erst kurz vorher erzeugt.
+/// Die Dateien dazu verschwinden mit dem Text.
+#[derive(Debug)]
+pub struct TextPractice {
+    chunks: VecDeque<String>,
+    dir: PathBuf,
+    speed: Speed,
+    tone_hz: f32,
+    sent: usize,
+    /// Nach jedem Stueck das Mitgeschriebene abfragen
+    pub copy_check: bool,
+}
+
+impl TextPractice {
+    pub fn new(chunks: Vec<String>, speed: Speed, tone_hz: f32, copy_check: bool) -> Self {
+        static COUNT: AtomicUsize = AtomicUsize::new(0);
+
+        Self {
+            chunks: chunks.into(),
+            // Pro Prozess und Text, sonst ueberschreiben sich zwei Instanzen gegenseitig die Stuecke
+            dir: speed
+                .cache_dir(tone_hz)
+                .join(format!("text-{}-{}", std::process::id(), COUNT.fetch_add(1, Ordering::Relaxed))),
+            speed,
+            tone_hz,
+            sent: 0,
+            copy_check,
+        }
+    }
+
+    /// Liest eine Textdatei und bereitet sie fuer die aktive Zeichenauswahl vor.
+    pub fn load(
+        path: &Path,
+        settings: &TextSettings,
+        speed: Speed,
+        tone_hz: f32,
+        allows: impl Fn(&str) -> bool,
+    ) -> Result<Self, LibraryError> {
+        let bytes = fs::read(path).map_err(|err| LibraryError::FileUnreadable(path.to_path_buf(), err))?;
+        let text = prepare(&String::from_utf8_lossy(&bytes), settings.policy, allows);
+
+        if text.is_empty() {
+            return Err(LibraryError::NothingToSend(path.to_path_buf()));
+        }
+
+        Ok(Self::new(chunks(&text, settings.words_per_chunk), speed, tone_hz, settings.copy_check))
+    }
+
+    /// Die noch nicht gesendeten Stuecke.
+    pub fn chunks(&self) -> impl Iterator<Item = &str> {
+        self.chunks.iter().map(String::as_str)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.chunks.len()
+    }
+
+    pub fn sent(&self) -> usize {
+        self.sent
+    }
+
+    /// Erzeugt den Ton fuer das naechste Stueck; `None`, wenn der Text zu Ende ist.
+    pub fn next_sound(&mut self) -> Option<Result<Sound, LibraryError>> {
+        let chunk = self.chunks.pop_front()?;
+        let path = self.dir.join(format!("chunk-{}.wav", self.sent % FILES));
+        self.sent += 1;
+
+        let bytes = morse::wav_bytes(&morse::render_text(&chunk, self.speed, self.tone_hz));
+        let written = fs::create_dir_all(&self.dir)
+            .and_then(|()| fs::write(&path, bytes))
+            .map_err(|err| LibraryError::Unwritable(path.clone(), err));
+
+        Some(written.map(|()| Sound {
+            path,
+            name: chunk,
+            signal: Signal::synthesized(self.speed, self.tone_hz),
+        }))
+    }
+}
+
+impl Drop for TextPractice {
+    fn drop(&mut self) {
+        // Fehlt, wenn noch nichts gesendet wurde
+        let _ = fs::remove_dir_all(&self.dir);
+    }
+}
+
+/// Einstellungen fuer das Senden von Texten.
+#[derive(Clone, Debug)]
+pub struct TextSettings {
+    pub words_per_chunk: usize,
+    pub policy: UnknownPolicy,
+    pub copy_check: bool,
+}
+
+impl Default for TextSettings {
+    fn default() -> Self {
+        Self {
+            words_per_chunk: 5,
+            policy: UnknownPolicy::default(),
+            copy_check: true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::morse::{DEFAULT_TONE_HZ, DEFAULT_WPM};
+
+    fn text(chunks: &[&str]) -> TextPractice {
+        let speed = Speed {
+            wpm: DEFAULT_WPM,
+            farnsworth: None,
+        };
+        TextPractice::new(chunks.iter().map(|chunk| chunk.to_string()).collect(), speed, DEFAULT_TONE_HZ, true)
+    }
+
+    #[test]
+    fn files_are_removed_with_the_text() {
+        let mut practice = text(&["cq cq", "de dl1abc"]);
+        let sound = practice.next_sound().unwrap().unwrap();
+        let dir = sound.path.parent().unwrap().to_path_buf();
+        assert!(sound.path.exists());
+
+        drop(practice);
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn texts_do_not_share_files() {
+        let mut first = text(&["cq"]);
+        let mut second = text(&["de"]);
+        let first_sound = first.next_sound().unwrap().unwrap();
+        let second_sound = second.next_sound().unwrap().unwrap();
+        assert_ne!(first_sound.path, second_sound.path);
+
+        drop(first);
+        assert!(second_sound.path.exists());
+    }
+}
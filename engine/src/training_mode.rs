@@ -0,0 +1,363 @@
+use std::collections::BTreeSet;
+use rand::{SeedableRng, prelude::IndexedRandom, rngs::StdRng};
+
+use crate::confusion::ConfusionMatrix;
+use crate::tr;
+
+/// Buchstaben, die erfahrungsgemaess am laengsten brauchen.
+pub const HARD_LETTERS: [&str; 5] = ["y", "q", "z", "j", "x"];
+
+/// Zeichen, die sich fuer Anfaenger aehnlich anhoeren.
+pub const CONFUSABLE_GROUPS: [&[&str]; 4] = [&["s", "h", "5"], &["u", "v", "4"], &["d", "b", "6"], &["w", "j", "1"]];
+
+// Wie viele Runden bei einer Gruppe geblieben wird, bevor gewechselt wird
+const ROUNDS_PER_GROUP: u32 = 8;
+
+// Wie viele der eigenen haeufigsten Verwechslungen als Paare dazukommen
+const OWN_PAIRS: usize = 4;
+
+/// Reihenfolge der Zeichen nach Koch (wie bei LCWO); Lektion 1 sind die ersten beiden.
+pub const KOCH_ORDER: [&str; 41] = [
+    "k", "m", "u", "r", "e", "s", "n", "a", "p", "t", "l", "w", "i", "punkt", "j", "z", "ist_gleich", "f", "o", "y",
+    "komma", "v", "g", "5", "schraestrich", "q", "9", "2", "h", "3", "8", "b", "fragezeichen", "4", "7", "c", "1", "d",
+    "6", "0", "x",
+];
+
+const PROSIGNS: [&str; 11] = ["ar", "as", "bk", "bt", "cq", "sk", "kn", "sn", "ve", "ka", "sos"];
+
+// Satzzeichen werden ueber ihren deutschen Dateinamen erkannt
+const PUNCTUATION: [(&str, &str); 7] = [
+    ("punkt", "."),
+    ("komma", ","),
+    ("fragezeichen", "?"),
+    ("ist_gleich", "="),
+    ("schraestrich", "/"),
+    ("bindestrich", "-"),
+    ("doppelpunkt", ":"),
+];
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum CharClass {
+    Letter,
+    Digit,
+    Punctuation,
+    Prosign,
+    Other,
+}
+
+pub fn classify(name: &str) -> CharClass {
+    let lower = name.to_lowercase();
+    let mut chars = lower.chars();
+
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_digit() => CharClass::Digit,
+        (Some(c), None) if c.is_alphabetic() => CharClass::Letter,
+        _ if PUNCTUATION.iter().any(|(file, _)| *file == lower) => CharClass::Punctuation,
+        _ if PROSIGNS.contains(&lower.as_str()) => CharClass::Prosign,
+        _ => CharClass::Other,
+    }
+}
+
+/// Anzeigename eines Zeichens, z.B. "?" fuer "fragezeichen".
+pub fn label(name: &str) -> String {
+    let lower = name.to_lowercase();
+    PUNCTUATION
+        .iter()
+        .find(|(file, _)| *file == lower)
+        .map(|(_, symbol)| symbol.to_string())
+        .unwrap_or_else(|| name.to_uppercase())
+}
+
+/// Umkehrung von `label`: "?" wird zu "fragezeichen", alles andere kleingeschrieben.
+pub fn name_of(symbol: &str) -> String {
+    PUNCTUATION
+        .iter()
+        .find(|(_, known)| *known == symbol)
+        .map(|(file, _)| file.to_string())
+        .unwrap_or_else(|| symbol.to_lowercase())
+}
+
+/// Zeichen der Koch-Lektion `lesson`, beginnend bei 1.
+pub fn koch_lesson(lesson: usize) -> Vec<String> {
+    lesson_in(&KOCH_ORDER, lesson)
+}
+
+/// Wie `koch_lesson`, aber mit einer eigenen Reihenfolge.
+pub fn lesson_in(order: &[impl AsRef<str>], lesson: usize) -> Vec<String> {
+    order.iter().take(lesson + 1).map(|name| name.as_ref().to_string()).collect()
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Preset {
+    All,
+    Letters,
+    Digits,
+    Punctuation,
+    Prosigns,
+    HardLetters,
+    Custom,
+}
+
+impl Preset {
+    pub const BUTTONS: [Preset; 6] = [
+        Preset::All,
+        Preset::Letters,
+        Preset::Digits,
+        Preset::Punctuation,
+        Preset::Prosigns,
+        Preset::HardLetters,
+    ];
+
+    pub fn label(self) -> String {
+        tr!(match self {
+            Preset::All => "preset-all",
+            Preset::Letters => "preset-letters",
+            Preset::Digits => "preset-digits",
+            Preset::Punctuation => "preset-punctuation",
+            Preset::Prosigns => "preset-prosigns",
+            Preset::HardLetters => "preset-hard-letters",
+            Preset::Custom => "preset-custom",
+        })
+    }
+
+    fn includes(self, name: &str) -> bool {
+        match self {
+            Preset::All => true,
+            Preset::Letters => classify(name) == CharClass::Letter,
+            Preset::Digits => classify(name) == CharClass::Digit,
+            Preset::Punctuation => classify(name) == CharClass::Punctuation,
+            Preset::Prosigns => classify(name) == CharClass::Prosign,
+            Preset::HardLetters => HARD_LETTERS.contains(&name.to_lowercase().as_str()),
+            Preset::Custom => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DrillMode {
+    /// Zufaellige Zeichen aus der Auswahl
+    Random,
+    /// Abwechselnd Zeichen einer Gruppe, die sich aehnlich anhoeren
+    Confusables,
+    /// Zufaellige Zeichen als fortlaufender Strom, mitgeschrieben wird nebenher
+    CopyBehind,
+}
+
+impl DrillMode {
+    pub const BUTTONS: [DrillMode; 3] = [DrillMode::Random, DrillMode::Confusables, DrillMode::CopyBehind];
+
+    pub fn label(self) -> String {
+        tr!(match self {
+            DrillMode::Random => "drill-random",
+            DrillMode::Confusables => "drill-confusables",
+            DrillMode::CopyBehind => "drill-copy-behind",
+        })
+    }
+}
+
+/// Legt fest, welche Zeichen bei der Auswahl einer Runde in Frage kommen.
+pub struct TrainingMode {
+    pub preset: Preset,
+    pub drill: DrillMode,
+    available: Vec<String>,
+    selected: BTreeSet<String>,
+    own_pairs: Vec<(String, String)>,
+    drill_group: Vec<String>,
+    drill_rounds: u32,
+    last: Option<String>,
+    rng: StdRng,
+}
+
+impl TrainingMode {
+    pub fn new(mut available: Vec<String>) -> Self {
+        available.sort_by_key(|name| (classify(name), name.len(), name.to_lowercase()));
+        available.dedup_by_key(|name| name.to_lowercase());
+
+        let mut mode = Self {
+            preset: Preset::All,
+            drill: DrillMode::Random,
+            available,
+            selected: BTreeSet::new(),
+            own_pairs: Vec::new(),
+            drill_group: Vec::new(),
+            drill_rounds: 0,
+            last: None,
+            rng: StdRng::from_os_rng(),
+        };
+        mode.apply_preset(Preset::All);
+        mode
+    }
+
+    pub fn available(&self) -> &[String] {
+        &self.available
+    }
+
+    pub fn apply_preset(&mut self, preset: Preset) {
+        self.preset = preset;
+        self.selected = self
+            .available
+            .iter()
+            .filter(|name| preset.includes(name))
+            .map(|name| name.to_lowercase())
+            .collect();
+        self.restart_drill();
+    }
+
+    pub fn toggle(&mut self, name: &str) {
+        let key = name.to_lowercase();
+        if !self.selected.remove(&key) {
+            self.selected.insert(key);
+        }
+        self.preset = Preset::Custom;
+        self.restart_drill();
+    }
+
+    /// Waehlt genau die angegebenen Zeichen aus; liefert die, die es nicht gibt.
+    pub fn select(&mut self, names: &[String]) -> Vec<String> {
+        let (known, unknown): (Vec<&String>, Vec<&String>) = names.iter().partition(|name| self.is_available(name));
+
+        self.selected = known.into_iter().map(|name| name.to_lowercase()).collect();
+        self.preset = Preset::Custom;
+        self.restart_drill();
+        unknown.into_iter().cloned().collect()
+    }
+
+    /// Macht die Zeichenfolge reproduzierbar.
+    pub fn seed(&mut self, seed: u64) {
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+
+    pub fn allows(&self, name: &str) -> bool {
+        self.selected.contains(&name.to_lowercase())
+    }
+
+    pub fn selected_count(&self) -> usize {
+        self.selected.len()
+    }
+
+    pub fn set_drill(&mut self, drill: DrillMode) {
+        self.drill = drill;
+        self.restart_drill();
+    }
+
+    // Die naechste Runde waehlt die Verwechslergruppe neu, z.B. nach einer geaenderten Auswahl
+    fn restart_drill(&mut self) {
+        self.drill_group.clear();
+        self.drill_rounds = 0;
+    }
+
+    fn is_available(&self, name: &str) -> bool {
+        self.available.iter().any(|available| available.eq_ignore_ascii_case(name))
+    }
+
+    /// Uebernimmt die haeufigsten eigenen Verwechslungen fuer das Verwechsler-Training.
+    pub fn learn(&mut self, confusion: &ConfusionMatrix) {
+        self.own_pairs = confusion
+            .pairs()
+            .into_iter()
+            .take(OWN_PAIRS)
+            .map(|(expected, typed, _)| (expected.to_string(), typed.to_string()))
+            .collect();
+    }
+
+    /// Feste Verwechslergruppen plus die haeufigsten eigenen Verwechslungen,
+    /// jeweils beschraenkt auf die ausgewaehlten Zeichen.
+    pub fn drill_groups(&self) -> Vec<Vec<String>> {
+        let mut groups: Vec<Vec<String>> = CONFUSABLE_GROUPS
+            .iter()
+            .map(|group| group.iter().map(|name| name.to_string()).collect())
+            .collect();
+
+        for (expected, typed) in &self.own_pairs {
+            let known = groups
+                .iter()
+                .any(|group| group.iter().any(|n| n == expected) && group.iter().any(|n| n == typed));
+            if !known {
+                groups.push(vec![expected.to_string(), typed.to_string()]);
+            }
+        }
+
+        groups
+            .into_iter()
+            .map(|group| group.into_iter().filter(|name| self.allows(name)).collect::<Vec<_>>())
+            .filter(|group| group.len() >= 2)
+            .collect()
+    }
+
+    /// Waehlt das Zeichen fuer die naechste Runde (kleingeschrieben).
+    pub fn next_character(&mut self) -> Option<String> {
+        if self.drill == DrillMode::Confusables && self.drill_rounds == 0 {
+            self.drill_group = self.drill_groups().choose(&mut self.rng).cloned().unwrap_or_default();
+            // Ohne Gruppe wird in der naechsten Runde wieder gesucht, z.B. nach einer neuen Verwechslung
+            self.drill_rounds = if self.drill_group.is_empty() { 0 } else { ROUNDS_PER_GROUP };
+        }
+
+        let candidates: Vec<String> = if self.drill == DrillMode::Confusables && !self.drill_group.is_empty() {
+            self.drill_rounds -= 1;
+            self.drill_group
+                .iter()
+                .filter(|name| self.last.as_ref() != Some(*name))
+                .cloned()
+                .collect()
+        } else {
+            self.selected.iter().cloned().collect()
+        };
+
+        let next = candidates.choose(&mut self.rng).cloned();
+        self.last = next.clone();
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mode() -> TrainingMode {
+        let names = ["s", "h", "5", "u", "v", "4", "d", "b", "6", "k", "m"];
+        let mut mode = TrainingMode::new(names.iter().map(|name| name.to_string()).collect());
+        mode.seed(1);
+        mode
+    }
+
+    #[test]
+    fn drill_groups_only_contain_selected_characters() {
+        let mut mode = mode();
+        mode.select(&["s".to_string(), "h".to_string(), "u".to_string(), "v".to_string(), "d".to_string()]);
+
+        assert_eq!(mode.drill_groups(), [vec!["s", "h"], vec!["u", "v"]]);
+    }
+
+    #[test]
+    fn drill_plays_no_deselected_character() {
+        let mut mode = mode();
+        mode.set_drill(DrillMode::Confusables);
+        mode.next_character();
+
+        mode.toggle("5");
+        mode.toggle("4");
+        for _ in 0..100 {
+            let next = mode.next_character().unwrap();
+            assert!(mode.allows(&next), "{}", next);
+        }
+    }
+
+    #[test]
+    fn drill_starts_with_the_first_confusion() {
+        let mut mode = mode();
+        mode.select(&["k".to_string(), "m".to_string(), "s".to_string()]);
+        mode.set_drill(DrillMode::Confusables);
+        assert!(mode.drill_groups().is_empty());
+        for _ in 0..3 {
+            mode.next_character();
+        }
+
+        let mut confusion = ConfusionMatrix::default();
+        confusion.record("k", "m");
+        mode.learn(&confusion);
+        for _ in 0..ROUNDS_PER_GROUP {
+            let next = mode.next_character().unwrap();
+            assert!(next == "k" || next == "m", "{}", next);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+//! Die Ausgabe ohne Soundkarte spielt genau so lange, wie der Sound dauert, und schneidet ihn auf Wunsch vollstaendig mit.
+
+use std::fs;
+use std::time::Duration;
+
+use cw_engine::loopback::Samples;
+use cw_engine::{test_util, AudioBackend, LoopbackBackend, Sound};
+
+const STEP: Duration = Duration::from_millis(10);
+
+fn sound(name: &str) -> (Sound, Samples) {
+    let sound = test_util::sound(&test_util::temp_dir(), name);
+    let samples = Samples::decode(&fs::read(&sound.path).unwrap()).unwrap();
+    (sound, samples)
+}
+
+#[test]
+fn plays_for_the_length_of_the_sound() {
+    let (sound, samples) = sound("k");
+    let mut audio = LoopbackBackend::capturing();
+    audio.play(&sound);
+
+    let mut played = Duration::ZERO;
+    while audio.is_playing() {
+        audio.advance(STEP);
+        played += STEP;
+    }
+
+    assert!(played >= samples.duration() && played < samples.duration() + STEP);
+    assert_eq!(audio.captured(), [samples]);
+}
+
+#[test]
+fn nothing_is_played_while_paused() {
+    let (sound, samples) = sound("k");
+    let mut audio = LoopbackBackend::capturing();
+    audio.play(&sound);
+    audio.advance(STEP);
+    let captured = audio.captured()[0].data.len();
+
+    audio.pause();
+    audio.advance(samples.duration() * 2);
+    assert!(audio.is_playing());
+    assert_eq!(audio.captured()[0].data.len(), captured);
+
+    audio.resume();
+    audio.advance(samples.duration());
+    assert!(!audio.is_playing());
+    assert_eq!(audio.captured(), [samples]);
+}
+
+#[test]
+fn stop_ends_the_capture() {
+    let (sound, samples) = sound("k");
+    let mut audio = LoopbackBackend::capturing();
+    audio.play(&sound);
+    audio.advance(samples.duration() / 2);
+    audio.stop();
+    audio.advance(samples.duration());
+
+    assert!(!audio.is_playing());
+    assert!(audio.captured()[0].data.len() < samples.data.len());
+}
+
+#[test]
+fn unreadable_sound_is_reported() {
+    let mut audio = LoopbackBackend::capturing();
+    audio.play(&Sound::new("fehlt.wav".into(), "a"));
+
+    assert!(!audio.is_playing());
+    assert!(audio.last_error().is_some_and(|error| error.contains("fehlt.wav")));
+    assert!(audio.captured().is_empty());
+}
+
+#[test]
+fn nothing_is_kept_without_capturing() {
+    let (sound, samples) = sound("k");
+    let mut audio = LoopbackBackend::new();
+    audio.play(&sound);
+    audio.advance(samples.duration() / 2);
+    assert!(audio.is_playing());
+
+    audio.advance(samples.duration());
+    assert!(!audio.is_playing());
+    assert!(audio.captured().is_empty());
+}
+
+#[test]
+fn shipped_mp3_sounds_are_decoded() {
+    let bytes = fs::read(concat!(env!("CARGO_MANIFEST_DIR"), "/../assets/sounds/A.mp3")).unwrap();
+    let samples = Samples::decode(&bytes).unwrap();
+
+    assert!(samples.channels > 0 && samples.sample_rate > 0);
+    assert!(samples.duration() > Duration::from_millis(50));
+}
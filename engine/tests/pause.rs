@@ -0,0 +1,139 @@
+//! Waehrend einer Pause darf sich am Ablauf nichts aendern, egal in welchem Zustand sie beginnt.
+
+use std::time::Duration;
+
+use cw_engine::{test_util, Effect, PlayState, SessionEvent, Timings, TrainingSession};
+
+const STEP: Duration = Duration::from_millis(10);
+
+fn session() -> TrainingSession {
+    TrainingSession::new(test_util::library(&["a", "b"]), Timings::default())
+}
+
+fn current(session: &TrainingSession) -> String {
+    session.current().map(|sound| sound.name.clone()).unwrap_or_default()
+}
+
+/// Eine Sitzung im gewuenschten Zustand, ohne offene Effekte.
+fn session_in(state: PlayState) -> TrainingSession {
+    let mut session = session();
+    let timings = session.timings().clone();
+
+    if state != PlayState::ReadyToPlay {
+        session.tick(STEP);
+    }
+    if matches!(
+        state,
+        PlayState::WaitingForAnswer | PlayState::Pausing | PlayState::RepeatPausing | PlayState::RepeatPlaying
+    ) {
+        session.handle(SessionEvent::PlaybackStarted);
+        session.handle(SessionEvent::PlaybackFinished);
+    }
+    match state {
+        PlayState::Pausing => session.handle(SessionEvent::AnswerSubmitted(current(&session))),
+        PlayState::RepeatPausing | PlayState::RepeatPlaying => {
+            let wrong = if current(&session) == "a" { "b" } else { "a" };
+            session.handle(SessionEvent::AnswerSubmitted(wrong.to_string()));
+        }
+        PlayState::Stopped => session.handle(SessionEvent::Stop),
+        _ => {}
+    }
+    if state == PlayState::RepeatPlaying {
+        session.tick(timings.repeat_pause);
+    }
+
+    assert_eq!(session.state(), state);
+    session.take_effects();
+    session
+}
+
+const ALL_STATES: [PlayState; 7] = [
+    PlayState::ReadyToPlay,
+    PlayState::Playing,
+    PlayState::WaitingForAnswer,
+    PlayState::Pausing,
+    PlayState::RepeatPausing,
+    PlayState::RepeatPlaying,
+    PlayState::Stopped,
+];
+
+#[test]
+fn nothing_changes_while_paused() {
+    for state in ALL_STATES {
+        let mut session = session_in(state);
+        session.handle(SessionEvent::Pause);
+        session.take_effects();
+
+        let prompt = session.prompt().clone();
+        let training_time = session.training_time();
+        let counts = (session.correct_count(), session.wrong_count());
+
+        for event in [
+            SessionEvent::PlaybackStarted,
+            SessionEvent::PlaybackFinished,
+            SessionEvent::PlaybackFailed("kaputt".to_string()),
+            SessionEvent::AnswerSubmitted(current(&session)),
+            SessionEvent::AnswerSubmitted("x".to_string()),
+            SessionEvent::Typed('a'),
+            SessionEvent::Erase,
+            SessionEvent::Replay,
+            SessionEvent::Skip,
+        ] {
+            session.handle(event);
+            session.tick(Duration::from_secs(60));
+        }
+
+        assert_eq!(session.state(), state, "{:?}", state);
+        assert_eq!(session.prompt(), &prompt, "{:?}", state);
+        assert_eq!(session.training_time(), training_time, "{:?}", state);
+        assert_eq!((session.correct_count(), session.wrong_count()), counts, "{:?}", state);
+        assert!(session.take_effects().is_empty(), "{:?}", state);
+    }
+}
+
+#[test]
+fn stopped_session_is_not_paused() {
+    let mut session = session_in(PlayState::Stopped);
+    session.handle(SessionEvent::Pause);
+
+    assert!(!session.is_paused());
+    assert!(session.take_effects().is_empty());
+}
+
+#[test]
+fn stop_ends_a_paused_session() {
+    let mut session = session_in(PlayState::Playing);
+    session.handle(SessionEvent::Pause);
+    session.handle(SessionEvent::Stop);
+
+    assert_eq!(session.state(), PlayState::Stopped);
+    assert!(!session.is_paused());
+}
+
+#[test]
+fn timers_continue_where_they_stopped() {
+    let mut session = session_in(PlayState::Pausing);
+    let pause = session.timings().pause;
+
+    session.tick(pause / 2);
+    session.handle(SessionEvent::Pause);
+    session.tick(pause * 10);
+    session.handle(SessionEvent::Resume);
+    session.tick(pause / 2 - STEP);
+    assert_eq!(session.state(), PlayState::Pausing);
+
+    session.tick(STEP);
+    assert_eq!(session.state(), PlayState::ReadyToPlay);
+}
+
+#[test]
+fn failure_during_pause_applies_after_resume() {
+    let mut session = session_in(PlayState::Playing);
+    session.handle(SessionEvent::Pause);
+    session.handle(SessionEvent::PlaybackFailed("kaputt".to_string()));
+    assert_eq!(session.state(), PlayState::Playing);
+
+    session.handle(SessionEvent::Resume);
+    assert_eq!(session.state(), PlayState::Pausing);
+    assert!(session.take_effects().contains(&Effect::StopAudio));
+}
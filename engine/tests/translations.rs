@@ -0,0 +1,107 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use cw_engine::i18n::Language;
+use fluent_bundle::{FluentBundle, FluentResource};
+
+const SOURCES: [&str; 3] = ["src", "engine/src", "tui/src"];
+
+fn ftl(language: Language) -> String {
+    let path = format!("{}/locales/{}.ftl", env!("CARGO_MANIFEST_DIR"), language.code());
+    fs::read_to_string(&path).unwrap_or_else(|err| panic!("{}: {}", path, err))
+}
+
+/// Ids aller Texte einer ftl-Datei; Fortsetzungszeilen sind eingerueckt, Kommentare beginnen mit '#'.
+fn message_ids(source: &str) -> BTreeSet<String> {
+    source
+        .lines()
+        .filter(|line| line.starts_with(|c: char| c.is_ascii_lowercase()))
+        .filter_map(|line| line.split_once('=').map(|(id, _)| id.trim().to_string()))
+        .collect()
+}
+
+fn rust_files(dir: &Path, files: &mut Vec<String>) {
+    for entry in fs::read_dir(dir).unwrap().map(Result::unwrap) {
+        let path = entry.path();
+        if path.is_dir() {
+            rust_files(&path, files);
+        } else if path.extension().is_some_and(|extension| extension == "rs") {
+            files.push(fs::read_to_string(&path).unwrap());
+        }
+    }
+}
+
+/// Die Zeichenketten im ersten Argument eines `tr!`, auch in `match` und `if`.
+/// Zur Laufzeit gebaute Ids wie "spell-3" fallen durch; sie deckt der Vergleich der Sprachen ab.
+fn used_ids(code: &str) -> BTreeSet<String> {
+    let code: String = code
+        .lines()
+        .filter(|line| !line.trim_start().starts_with("//"))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    let mut ids = BTreeSet::new();
+    for (start, _) in code.match_indices("tr!(") {
+        let mut depth = 0;
+        let mut literal: Option<String> = None;
+        let mut escaped = false;
+
+        for c in code[start + 4..].chars() {
+            if let Some(text) = literal.as_mut() {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => {
+                        let text = literal.take().unwrap();
+                        if !text.is_empty() && text.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-') {
+                            ids.insert(text);
+                        }
+                    }
+                    _ => text.push(c),
+                }
+                continue;
+            }
+
+            match c {
+                '"' => literal = Some(String::new()),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' if depth == 0 => break,
+                ')' | ']' | '}' => depth -= 1,
+                ',' if depth == 0 => break,
+                _ => {}
+            }
+        }
+    }
+    ids
+}
+
+#[test]
+fn languages_have_the_same_texts() {
+    let german = message_ids(&ftl(Language::De));
+    assert!(german.len() > 100, "{} Texte", german.len());
+
+    for language in Language::ALL {
+        let ids = message_ids(&ftl(language));
+        let missing: Vec<_> = german.difference(&ids).collect();
+        let extra: Vec<_> = ids.difference(&german).collect();
+        assert!(missing.is_empty() && extra.is_empty(), "{}: fehlt {:?}, zuviel {:?}", language.code(), missing, extra);
+    }
+}
+
+#[test]
+fn every_used_text_is_translated() {
+    let mut files = Vec::new();
+    for dir in SOURCES {
+        rust_files(&Path::new(env!("CARGO_MANIFEST_DIR")).join("..").join(dir), &mut files);
+    }
+    let used: BTreeSet<String> = files.iter().flat_map(|code| used_ids(code)).collect();
+    assert!(used.contains("prompt-ready") && used.contains("on"), "{:?}", used);
+
+    for language in Language::ALL {
+        let resource = FluentResource::try_new(ftl(language)).unwrap_or_else(|(_, errors)| panic!("{:?}", errors));
+        let mut bundle = FluentBundle::<FluentResource>::default();
+        bundle.add_resource(resource).unwrap();
+
+        let missing: Vec<_> = used.iter().filter(|id| !bundle.has_message(id)).collect();
+        assert!(missing.is_empty(), "{}: fehlt {:?}", language.code(), missing);
+    }
+}
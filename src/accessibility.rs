@@ -0,0 +1,225 @@
+use std::collections::VecDeque;
+
+use accesskit::{Live, Node as AccessNode, Role};
+use bevy::a11y::AccessibilityNode;
+use bevy::audio::AudioSource;
+use bevy::input_focus::tab_navigation::{TabGroup, TabIndex, TabNavigationPlugin};
+use bevy::input_focus::{InputDispatchPlugin, InputFocus, InputFocusVisible};
+use bevy::prelude::*;
+use bevy::ui::UiSystem;
+use cw_engine::accessibility::{cue, AnswerBack, Speech};
+use cw_engine::config::AccessibilityConfig;
+use cw_engine::morse::wav_bytes;
+use cw_engine::training_mode::label;
+use cw_engine::{tr, Grade};
+
+use crate::training::audio_handle;
+use crate::Trainer;
+
+/// Rueckmeldung nach jeder Antwort fuer Benutzer, die die Anzeige nicht sehen.
+#[derive(Resource)]
+pub struct Feedback {
+    pub enabled: bool,
+    answer_back: AnswerBack,
+    speech: Option<Speech>,
+    pending: Vec<Grade>,
+    queue: VecDeque<Handle<AudioSource>>,
+    interrupted: bool,
+}
+
+impl Feedback {
+    pub fn new(config: &AccessibilityConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            answer_back: config.answer_back,
+            speech: Speech::new(&config.speech_command),
+            pending: Vec::new(),
+            queue: VecDeque::new(),
+            interrupted: false,
+        }
+    }
+
+    pub fn graded(&mut self, grade: &Grade) {
+        if self.enabled || self.answer_back != AnswerBack::Off {
+            self.pending.push(grade.clone());
+        }
+    }
+
+    /// Das naechste Zeichen kommt; eine noch laufende Rueckmeldung wird abgebrochen.
+    pub fn interrupt(&mut self) {
+        self.interrupted = true;
+    }
+}
+
+#[derive(Component)]
+struct FeedbackAudio;
+
+/// Text, dessen Aenderungen ein Screenreader sofort ansagen soll, z.B. die Anzeige im Training.
+#[derive(Component)]
+pub struct LiveText;
+
+pub fn plugin(app: &mut App) {
+    app.add_plugins((InputDispatchPlugin, TabNavigationPlugin))
+        .add_systems(PreUpdate, activate_focused_button.after(UiSystem::Focus))
+        .add_systems(Update, (make_focusable, drop_stale_focus, show_focus).chain())
+        .add_systems(Update, (expose_text, play_feedback));
+}
+
+/// Jeder Button ist mit Tab erreichbar; jeder Screen bildet eine eigene Gruppe.
+fn make_focusable(
+    mut commands: Commands,
+    button_query: Query<Entity, Added<Button>>,
+    root_query: Query<Entity, (Added<Node>, Without<ChildOf>)>,
+) {
+    for entity in button_query.iter() {
+        commands.entity(entity).insert(TabIndex(0));
+    }
+
+    for entity in root_query.iter() {
+        commands.entity(entity).insert(TabGroup::new(0));
+    }
+}
+
+/// Beim Wechsel des Screens verschwindet der fokussierte Button.
+fn drop_stale_focus(mut focus: ResMut<InputFocus>, entities: Query<()>) {
+    if let Some(entity) = focus.0
+        && entities.get(entity).is_err()
+    {
+        focus.clear();
+    }
+}
+
+fn show_focus(
+    mut commands: Commands,
+    focus: Res<InputFocus>,
+    visible: Res<InputFocusVisible>,
+    button_query: Query<(Entity, Has<Outline>), With<Button>>,
+) {
+    if !focus.is_changed() && !visible.is_changed() {
+        return;
+    }
+
+    for (entity, outlined) in button_query.iter() {
+        let focused = visible.0 && focus.0 == Some(entity);
+
+        if focused && !outlined {
+            commands.entity(entity).insert(Outline::new(Val::Px(3.0), Val::Px(2.0), Color::WHITE));
+        } else if !focused && outlined {
+            commands.entity(entity).remove::<Outline>();
+        }
+    }
+}
+
+/// Enter oder Leertaste loesen den fokussierten Button aus, als waere er angeklickt worden.
+fn activate_focused_button(
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    mut interaction_query: Query<&mut Interaction, With<Button>>,
+    mut activated: Local<Option<Entity>>,
+) {
+    // Ohne Mausklick setzt Bevy den Zustand nicht selbst zurueck
+    if let Some(entity) = activated.take()
+        && let Ok(mut interaction) = interaction_query.get_mut(entity)
+    {
+        interaction.set_if_neq(Interaction::None);
+    }
+
+    if !keys.just_pressed(KeyCode::Enter) && !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    if let Some(entity) = focus.0
+        && let Ok(mut interaction) = interaction_query.get_mut(entity)
+    {
+        *interaction = Interaction::Pressed;
+        *activated = Some(entity);
+    }
+}
+
+/// Haelt ausser Button-Beschriftungen, die Bevy selbst uebernimmt, alle Texte fuer Screenreader bereit.
+#[allow(clippy::type_complexity)]
+fn expose_text(
+    mut commands: Commands,
+    mut text_query: Query<(Entity, &Text, Option<&mut AccessibilityNode>, Has<LiveText>, Option<&ChildOf>), Changed<Text>>,
+    button_query: Query<(), With<Button>>,
+) {
+    for (entity, text, node, live, parent) in text_query.iter_mut() {
+        if parent.is_some_and(|parent| button_query.contains(parent.parent())) {
+            continue;
+        }
+
+        match node {
+            Some(mut node) => node.set_value(text.0.as_str()),
+            None => {
+                let mut node = AccessNode::new(Role::Label);
+                node.set_value(text.0.as_str());
+                if live {
+                    node.set_live(Live::Polite);
+                }
+                commands.entity(entity).insert(AccessibilityNode::from(node));
+            }
+        }
+    }
+}
+
+/// Spielt Rueckmeldeton und Antwort nacheinander ab.
+fn play_feedback(
+    mut commands: Commands,
+    mut feedback: ResMut<Feedback>,
+    trainer: Res<Trainer>,
+    asset_server: Res<AssetServer>,
+    mut audio_sources: ResMut<Assets<AudioSource>>,
+    playing_query: Query<Entity, With<FeedbackAudio>>,
+    mut cues: Local<Option<[Handle<AudioSource>; 2]>>,
+) {
+    if feedback.interrupted {
+        feedback.interrupted = false;
+        feedback.queue.clear();
+        for entity in playing_query.iter() {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let [correct_cue, wrong_cue] = cues
+        .get_or_insert_with(|| {
+            [true, false].map(|correct| audio_sources.add(AudioSource { bytes: wav_bytes(&cue(correct)).into() }))
+        })
+        .clone();
+
+    for grade in std::mem::take(&mut feedback.pending) {
+        if feedback.enabled {
+            let handle = if grade.correct { correct_cue.clone() } else { wrong_cue.clone() };
+            feedback.queue.push_back(handle);
+        }
+
+        match feedback.answer_back {
+            AnswerBack::Off => {}
+            AnswerBack::Morse => {
+                if let Some(sound) = trainer.library().find(&grade.expected)
+                    && let Ok(handle) = audio_handle(sound, &asset_server, &mut audio_sources)
+                {
+                    feedback.queue.push_back(handle);
+                }
+            }
+            AnswerBack::Speech => {
+                let text = tr!(
+                    if grade.correct { "a11y-correct" } else { "a11y-wrong" },
+                    answer = label(&grade.expected)
+                );
+
+                if let Some(speech) = &mut feedback.speech
+                    && let Err(err) = speech.say(&text)
+                {
+                    eprintln!("{}", tr!("error", error = tr!("error-speech", error = err.to_string())));
+                    feedback.speech = None;
+                }
+            }
+        }
+    }
+
+    if playing_query.is_empty()
+        && let Some(handle) = feedback.queue.pop_front()
+    {
+        commands.spawn((AudioPlayer::new(handle), PlaybackSettings::DESPAWN, FeedbackAudio));
+    }
+}
@@ -0,0 +1,281 @@
+use bevy::prelude::*;
+use cw_engine::training_mode::{label, DrillMode, Preset, TrainingMode};
+use cw_engine::tr;
+
+use crate::screen::{despawn_screen, screen_root, Screen};
+use crate::Trainer;
+
+#[derive(Component)]
+struct CharacterSelectScreen;
+
+#[derive(Component)]
+struct PresetButton(Preset);
+
+#[derive(Component)]
+struct CharToggle(String);
+
+#[derive(Component)]
+struct DrillButton(DrillMode);
+
+#[derive(Component)]
+struct DrillInfoText;
+
+#[derive(Component)]
+struct CloseCharacterSelectButton;
+
+#[derive(Component)]
+struct SelectionCountText;
+
+const SELECTED_COLOR: Color = Color::srgb(0.3, 0.7, 0.3);
+const UNSELECTED_COLOR: Color = Color::srgb(0.25, 0.25, 0.25);
+const PRESET_COLOR: Color = Color::srgb(0.2, 0.5, 0.8);
+const ACTIVE_PRESET_COLOR: Color = Color::srgb(0.3, 0.6, 0.9);
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::CharacterSelect), setup_character_select)
+        .add_systems(OnExit(Screen::CharacterSelect), despawn_screen::<CharacterSelectScreen>)
+        .add_systems(
+            Update,
+            (character_select_system, update_character_select).chain().run_if(in_state(Screen::CharacterSelect)),
+        );
+}
+
+fn setup_character_select(mut commands: Commands, trainer: Res<Trainer>) {
+    let mode = trainer.mode();
+
+    commands.spawn(screen_root(CharacterSelectScreen)).with_children(|panel| {
+        panel.spawn((
+            Text::new(selection_count_text(mode)),
+            TextFont {
+                font_size: 20.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+            SelectionCountText,
+        ));
+
+        panel.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(6.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        })
+        .with_children(|row| {
+            for drill in DrillMode::BUTTONS {
+                row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(115.0),
+                        height: Val::Px(32.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if drill == mode.drill { ACTIVE_PRESET_COLOR } else { PRESET_COLOR }),
+                    DrillButton(drill),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new(drill.label()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+
+            row.spawn((
+                Text::new(drill_info_text(mode)),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                DrillInfoText,
+            ));
+        });
+
+        panel.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            column_gap: Val::Px(6.0),
+            row_gap: Val::Px(6.0),
+            ..default()
+        })
+        .with_children(|row| {
+            for preset in Preset::BUTTONS {
+                row.spawn((
+                    Button,
+                    Node {
+                        width: Val::Px(115.0),
+                        height: Val::Px(32.0),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(PRESET_COLOR),
+                    PresetButton(preset),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new(preset.label()),
+                        TextFont {
+                            font_size: 16.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+
+        panel.spawn(Node {
+            width: Val::Percent(100.0),
+            max_width: Val::Px(740.0),
+            flex_wrap: FlexWrap::Wrap,
+            justify_content: JustifyContent::Center,
+            row_gap: Val::Px(4.0),
+            column_gap: Val::Px(4.0),
+            ..default()
+        })
+        .with_children(|grid| {
+            for name in mode.available() {
+                grid.spawn((
+                    Button,
+                    Node {
+                        min_width: Val::Px(50.0),
+                        height: Val::Px(36.0),
+                        padding: UiRect::horizontal(Val::Px(4.0)),
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BackgroundColor(if mode.allows(name) { SELECTED_COLOR } else { UNSELECTED_COLOR }),
+                    CharToggle(name.clone()),
+                ))
+                .with_children(|button| {
+                    button.spawn((
+                        Text::new(label(name)),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+
+        panel.spawn((
+            Button,
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(40.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.3, 0.7, 0.3)),
+            CloseCharacterSelectButton,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(tr!("button-done")),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+    });
+}
+
+fn selection_count_text(mode: &TrainingMode) -> String {
+    tr!("char-select-count", selected = mode.selected_count(), total = mode.available().len())
+}
+
+fn drill_info_text(mode: &TrainingMode) -> String {
+    match mode.drill {
+        DrillMode::Random => tr!("drill-random-info"),
+        DrillMode::CopyBehind => tr!("drill-copy-behind-info"),
+        DrillMode::Confusables => {
+            let groups: Vec<String> = mode
+                .drill_groups()
+                .iter()
+                .map(|group| group.iter().map(|name| label(name)).collect::<Vec<_>>().join("/"))
+                .collect();
+            tr!("drill-groups", groups = groups.join("  "))
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn character_select_system(
+    interaction_query: Query<
+        (&Interaction, Option<&PresetButton>, Option<&CharToggle>, Option<&DrillButton>, Option<&CloseCharacterSelectButton>),
+        Changed<Interaction>,
+    >,
+    mut trainer: ResMut<Trainer>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    for (interaction, preset, toggle, drill, close) in &interaction_query {
+        if *interaction != Interaction::Pressed {
+            continue;
+        }
+
+        let mode = trainer.mode_mut();
+
+        if let Some(PresetButton(preset)) = preset {
+            mode.apply_preset(*preset);
+        } else if let Some(CharToggle(name)) = toggle {
+            mode.toggle(name);
+        } else if let Some(DrillButton(drill)) = drill {
+            mode.set_drill(*drill);
+        } else if close.is_some() && mode.selected_count() > 0 {
+            next_screen.set(Screen::Home);
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn update_character_select(
+    trainer: Res<Trainer>,
+    mut toggle_query: Query<(&CharToggle, &mut BackgroundColor), (Without<PresetButton>, Without<DrillButton>)>,
+    mut preset_query: Query<(&PresetButton, &mut BackgroundColor), (Without<CharToggle>, Without<DrillButton>)>,
+    mut drill_query: Query<(&DrillButton, &mut BackgroundColor), (Without<CharToggle>, Without<PresetButton>)>,
+    mut count_query: Query<&mut Text, (With<SelectionCountText>, Without<DrillInfoText>)>,
+    mut drill_info_query: Query<&mut Text, (With<DrillInfoText>, Without<SelectionCountText>)>,
+) {
+    if !trainer.is_changed() {
+        return;
+    }
+
+    let mode = trainer.mode();
+
+    for (CharToggle(name), mut color) in toggle_query.iter_mut() {
+        *color = if mode.allows(name) { SELECTED_COLOR } else { UNSELECTED_COLOR }.into();
+    }
+
+    for (PresetButton(preset), mut color) in preset_query.iter_mut() {
+        *color = if *preset == mode.preset { ACTIVE_PRESET_COLOR } else { PRESET_COLOR }.into();
+    }
+
+    for (DrillButton(drill), mut color) in drill_query.iter_mut() {
+        *color = if *drill == mode.drill { ACTIVE_PRESET_COLOR } else { PRESET_COLOR }.into();
+    }
+
+    for mut text in count_query.iter_mut() {
+        **text = selection_count_text(mode);
+    }
+
+    for mut text in drill_info_query.iter_mut() {
+        **text = drill_info_text(mode);
+    }
+}
@@ -0,0 +1,372 @@
+use std::{fmt, path::{Path, PathBuf}, time::Duration};
+
+use clap::Parser;
+use cw_engine::{
+    morse::{Speed, DEFAULT_TONE_HZ, DEFAULT_WPM},
+    recording::{random_groups, AudioFormat, Item, Recording},
+    profile::DEFAULT_PROFILE,
+    import::{load_items, load_koch_order, ItemFormat},
+    training_mode::{koch_lesson, lesson_in, name_of, DrillMode, KOCH_ORDER},
+    tr, Config, ConfigError, LibraryError, Limits, Profile, SoundLibrary, TextPractice, TrainingSession,
+};
+
+use crate::accessibility::Feedback;
+use crate::layout::Layout;
+
+/// Morsezeichen nach Gehoer lernen.
+#[derive(Parser, Debug)]
+#[command(name = "cw-listen-trainer", version)]
+pub struct Cli {
+    /// Verzeichnis mit den Zeichen-Sounds
+    #[arg(long, value_name = "DIR", default_value = "assets/sounds")]
+    pub sounds: PathBuf,
+
+    /// Zeichen mit dieser Geschwindigkeit erzeugen statt die Sound-Dateien zu nutzen
+    #[arg(long, value_name = "WPM")]
+    pub wpm: Option<f32>,
+
+    /// Effektive Geschwindigkeit mit verlaengerten Pausen nach Farnsworth
+    #[arg(long, value_name = "WPM", requires = "wpm")]
+    pub farnsworth: Option<f32>,
+
+    /// Zu uebende Zeichen, z.B. "kmur5?" oder "a,b,ar,punkt"
+    #[arg(long, conflicts_with = "lesson")]
+    pub chars: Option<String>,
+
+    /// Koch-Lektion: 1 sind K und M, jede weitere Lektion bringt ein Zeichen dazu
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..KOCH_ORDER.len() as i64))]
+    pub lesson: Option<u32>,
+
+    /// Eigene Koch-Reihenfolge fuer --lesson, z.B. "KMURESNAPTLWI.JZ=FOY,VG5/Q92H38B?47C1D60X"
+    #[arg(long, value_name = "FILE", requires = "lesson")]
+    pub koch_order: Option<PathBuf>,
+
+    /// Woerter, Gruppen oder Rufzeichen aus einer Datei senden statt einzelner Zeichen
+    #[arg(long, value_name = "FILE", conflicts_with = "text")]
+    pub import: Option<PathBuf>,
+
+    /// Format fuer --import: words, groups (wie bei LCWO) oder callsigns (MASTER.SCP); sonst nach Endung
+    #[arg(long, value_name = "FORMAT", requires = "import")]
+    pub import_format: Option<ItemFormat>,
+
+    /// Name des Profils fuer Statistik und Fehlerbild
+    #[arg(long, default_value = DEFAULT_PROFILE, value_parser = parse_profile_name)]
+    pub profile: String,
+
+    /// Startwert fuer die Zeichenauswahl, damit eine Sitzung reproduzierbar ist
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Textdatei, die stueckweise gesendet wird, statt zufaelliger Zeichen
+    #[arg(long, value_name = "FILE")]
+    pub text: Option<PathBuf>,
+
+    /// Mitschreiben: Zeichen kommen fortlaufend, ohne auf Antworten zu warten
+    #[arg(long)]
+    pub copy_behind: bool,
+
+    /// Sitzungsdauer in Minuten
+    #[arg(long, value_name = "MINUTES")]
+    pub duration: Option<f32>,
+
+    /// Sitzung nach so vielen Zeichen bzw. Stuecken beenden
+    #[arg(long, value_name = "COUNT", value_parser = clap::value_parser!(u32).range(1..))]
+    pub items: Option<u32>,
+
+    /// Sitzung beenden, sobald die letzten Antworten diese Trefferquote in Prozent erreichen
+    #[arg(long, value_name = "PERCENT", value_parser = clap::value_parser!(u32).range(1..=100))]
+    pub target_accuracy: Option<u32>,
+
+    /// Einstellungsdatei statt der im Konfigurationsverzeichnis
+    #[arg(long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// Statt zu trainieren die Sitzung als WAV, FLAC oder OGG speichern, mit Loesungsblatt als .txt daneben
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Anzahl Gruppen in der Aufnahme, wenn kein Text angegeben ist
+    #[arg(long, default_value_t = 50, requires = "record")]
+    pub groups: usize,
+
+    /// Zeichen pro Gruppe in der Aufnahme
+    #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u32).range(1..=20), requires = "record")]
+    pub group_size: u32,
+
+    /// Ohne Fenster: Anzeige auf stdout, Antworten zeilenweise von stdin
+    #[arg(long)]
+    pub headless: bool,
+
+    /// Im Vollbild starten (F11 schaltet um)
+    #[arg(long, conflicts_with = "headless")]
+    pub fullscreen: bool,
+
+    /// Kleines Fenster mit engerem Layout
+    #[arg(long, conflicts_with = "headless")]
+    pub compact: bool,
+
+    /// Groessere Schrift und Bedienelemente
+    #[arg(long, conflicts_with = "headless")]
+    pub large_font: bool,
+
+    /// Ergebnis jeder Antwort zusaetzlich mit einem Ton melden
+    #[arg(long, conflicts_with = "headless")]
+    pub accessible: bool,
+
+    /// Ohne Soundkarte: jedes Zeichen laeuft stumm in seiner tatsaechlichen Laenge, z.B. auf Servern
+    #[arg(long)]
+    pub no_audio: bool,
+}
+
+#[derive(Debug)]
+pub enum SetupError {
+    Library(LibraryError),
+    Config(ConfigError),
+    Invalid(String),
+}
+
+impl fmt::Display for SetupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SetupError::Library(err) => write!(f, "{}", err),
+            SetupError::Config(err) => write!(f, "{}", err),
+            SetupError::Invalid(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl From<String> for SetupError {
+    fn from(message: String) -> Self {
+        SetupError::Invalid(message)
+    }
+}
+
+impl Cli {
+    pub fn config_path(&self) -> PathBuf {
+        self.config.clone().unwrap_or_else(Config::default_path)
+    }
+
+    pub fn load_config(&self) -> Result<Config, SetupError> {
+        Config::load(&self.config_path()).map_err(SetupError::Config)
+    }
+
+    /// Baut die Sitzung so, wie sie auf der Kommandozeile beschrieben ist.
+    pub fn session(&self, config: &Config) -> Result<TrainingSession, SetupError> {
+        let library = match self.speed()? {
+            Some(speed) => SoundLibrary::synthesize(speed.cache_dir(DEFAULT_TONE_HZ), speed, DEFAULT_TONE_HZ),
+            None => SoundLibrary::scan(&self.sounds),
+        }
+        .map_err(SetupError::Library)?;
+
+        let mut session = TrainingSession::new(library, config.timings.timings());
+        session.set_copy_behind(config.copy_behind.copy_behind());
+
+        if let Some(chars) = &self.chars {
+            let unknown = session.mode_mut().select(&parse_chars(chars));
+            if !unknown.is_empty() {
+                return Err(tr!("cli-unknown-characters", characters = unknown.join(", ")).into());
+            }
+        }
+
+        if let Some(lesson) = self.lesson {
+            // Spaetere Lektionen brauchen evtl. Zeichen, fuer die es keine Sound-Datei gibt
+            let lesson_chars = match &self.koch_order {
+                Some(path) => lesson_in(&load_koch_order(path).map_err(SetupError::Library)?, lesson as usize),
+                None => koch_lesson(lesson as usize),
+            };
+            let missing = session.mode_mut().select(&lesson_chars);
+            if !missing.is_empty() {
+                eprintln!("{}", tr!("warning-lesson-missing", characters = missing.join(", ")));
+            }
+        }
+
+        if let Some(seed) = self.seed {
+            session.mode_mut().seed(seed);
+        }
+
+        if let Some(path) = &self.text {
+            // Texte werden immer erzeugt, auch wenn die Zeichen aus Sound-Dateien kommen
+            let speed = self.render_speed()?;
+            let mode = session.mode();
+            let text = TextPractice::load(path, &config.text.settings(), speed, DEFAULT_TONE_HZ, |name| mode.allows(name))
+                .map_err(SetupError::Library)?;
+            session.set_text(Some(text));
+        }
+
+        if let Some(path) = &self.import {
+            let format = self.import_format.unwrap_or_else(|| ItemFormat::guess(path));
+            let mode = session.mode();
+            let items = load_items(path, format, |name| mode.allows(name), self.seed).map_err(SetupError::Library)?;
+            let text = TextPractice::new(items, self.render_speed()?, DEFAULT_TONE_HZ, config.text.copy_check);
+            session.set_text(Some(text));
+        }
+
+        if self.copy_behind {
+            session.mode_mut().drill = DrillMode::CopyBehind;
+        }
+
+        session.set_limits(self.overrides()?.limits(config));
+        session.set_replay_credit(config.session.replay_credit());
+
+        Ok(session)
+    }
+
+    /// Was von der Kommandozeile Vorrang vor der Einstellungsdatei hat.
+    pub fn overrides(&self) -> Result<Overrides, SetupError> {
+        let duration = match self.duration {
+            Some(minutes) if !(minutes > 0.0 && minutes.is_finite()) => {
+                return Err(tr!("cli-invalid-duration", value = minutes.to_string()).into());
+            }
+            minutes => minutes.map(|minutes| Duration::from_secs_f32(minutes * 60.0)),
+        };
+
+        Ok(Overrides {
+            duration,
+            items: self.items,
+            target_accuracy: self.target_accuracy.map(|percent| percent as f32 / 100.0),
+            fullscreen: self.fullscreen,
+            compact: self.compact,
+            large_font: self.large_font,
+            accessible: self.accessible,
+        })
+    }
+
+    /// Schreibt die Sitzung als Aufnahme: den Text, sonst zufaellige Gruppen aus der Auswahl.
+    /// Liefert den Pfad des Loesungsblatts.
+    pub fn record(&self, path: &Path, session: &mut TrainingSession, config: &Config) -> Result<PathBuf, SetupError> {
+        let format = AudioFormat::from_path(path)
+            .ok_or_else(|| tr!("cli-invalid-record-format", path = path.display().to_string()))?;
+
+        let items = match session.text() {
+            Some(text) => text.chunks().map(Item::from_text).collect(),
+            None => random_groups(session.mode_mut(), self.groups, self.group_size as usize),
+        };
+
+        let recording = Recording {
+            items,
+            speed: self.render_speed()?,
+            tone_hz: DEFAULT_TONE_HZ,
+            gap: config.timings.timings().pause,
+        };
+
+        recording
+            .write(path, format)
+            .map_err(|err| SetupError::Library(LibraryError::Unwritable(path.to_path_buf(), err)))
+    }
+
+    /// Geschwindigkeit fuer erzeugte Texte und Aufnahmen, auch ohne `--wpm`.
+    fn render_speed(&self) -> Result<Speed, String> {
+        Ok(self.speed()?.unwrap_or(Speed { wpm: DEFAULT_WPM, farnsworth: None }))
+    }
+
+    fn speed(&self) -> Result<Option<Speed>, String> {
+        let Some(wpm) = self.wpm else {
+            return Ok(None);
+        };
+
+        if !(5.0..=60.0).contains(&wpm) {
+            return Err(tr!("cli-invalid-wpm", value = wpm.to_string()));
+        }
+        if let Some(effective) = self.farnsworth
+            && !(effective > 0.0 && effective <= wpm)
+        {
+            return Err(tr!("cli-invalid-farnsworth", wpm = wpm.to_string(), value = effective.to_string()));
+        }
+
+        Ok(Some(Speed { wpm, farnsworth: self.farnsworth }))
+    }
+}
+
+/// Angaben auf der Kommandozeile; sie gelten auch weiter, wenn die Einstellungen gespeichert werden.
+#[derive(Clone, Default, Debug)]
+pub struct Overrides {
+    duration: Option<Duration>,
+    items: Option<u32>,
+    target_accuracy: Option<f32>,
+    fullscreen: bool,
+    compact: bool,
+    large_font: bool,
+    accessible: bool,
+}
+
+impl Overrides {
+    pub fn limits(&self, config: &Config) -> Limits {
+        let limits = config.session.limits();
+        Limits {
+            duration: self.duration.or(limits.duration),
+            items: self.items.or(limits.items),
+            target_accuracy: self.target_accuracy.or(limits.target_accuracy),
+        }
+    }
+
+    pub fn layout(&self, config: &Config) -> Layout {
+        let mut layout = Layout::new(&config.ui);
+        layout.fullscreen |= self.fullscreen;
+        layout.compact |= self.compact;
+        layout.large_font |= self.large_font;
+        layout
+    }
+
+    pub fn feedback(&self, config: &Config) -> Feedback {
+        let mut feedback = Feedback::new(&config.accessibility);
+        feedback.enabled |= self.accessible;
+        feedback
+    }
+}
+
+fn parse_profile_name(name: &str) -> Result<String, String> {
+    if Profile::is_valid_name(name) {
+        Ok(name.to_string())
+    } else {
+        Err(tr!("error-profile-name", name = name))
+    }
+}
+
+/// Mit Kommas getrennt sind auch Namen wie "ar" oder "punkt" moeglich, sonst zaehlt jedes Zeichen einzeln.
+fn parse_chars(chars: &str) -> Vec<String> {
+    if chars.contains(',') {
+        chars
+            .split(',')
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(name_of)
+            .collect()
+    } else {
+        chars
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .map(|c| name_of(&c.to_string()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saved_settings_keep_the_command_line() {
+        let cli = Cli::parse_from(["cw-listen-trainer", "--items", "10", "--compact", "--accessible"]);
+        let overrides = cli.overrides().unwrap();
+
+        // So wie nach dem Speichern im Einstellungsfenster
+        let mut config = Config::default();
+        config.session.minutes = 5;
+        config.session.items = 30;
+        config.ui.large_font = true;
+
+        let limits = overrides.limits(&config);
+        assert_eq!(limits.items, Some(10));
+        assert_eq!(limits.duration, Some(Duration::from_secs(300)));
+
+        let layout = overrides.layout(&config);
+        assert!(layout.compact && layout.large_font && !layout.fullscreen);
+        assert!(overrides.feedback(&config).enabled);
+    }
+
+    #[test]
+    fn invalid_duration_is_rejected() {
+        let cli = Cli::parse_from(["cw-listen-trainer", "--duration", "0"]);
+        assert!(cli.overrides().is_err());
+    }
+}
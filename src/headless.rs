@@ -0,0 +1,117 @@
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+    sync::mpsc::{self, TryRecvError},
+    thread,
+    time::{Duration, Instant},
+};
+
+use cw_engine::{tr, AudioBackend, Effect, LoopbackBackend, PlayState, Profile, Prompt, RodioBackend, SessionEvent, TrainingSession};
+
+const FRAME: Duration = Duration::from_millis(20);
+
+/// Sitzung ohne Fenster, z.B. fuer Skripte: jede Zeile auf stdin ist eine Antwort.
+/// Antworten, die vor dem Ende der Wiedergabe kommen, werden fuer das naechste Zeichen aufgehoben.
+/// Beim Mitschreiben zaehlt jedes Zeichen einer Zeile sofort als getippt.
+pub fn run(mut trainer: TrainingSession, mut profile: Profile, no_audio: bool) {
+    let mut audio: Box<dyn AudioBackend> = if no_audio {
+        Box::new(LoopbackBackend::new())
+    } else {
+        match RodioBackend::new() {
+            Ok(backend) => Box::new(backend),
+            Err(err) => {
+                eprintln!("{}", tr!("warning-no-audio", error = err));
+                Box::new(LoopbackBackend::new())
+            }
+        }
+    };
+
+    let (sender, lines) = mpsc::channel();
+    thread::spawn(move || {
+        for line in io::stdin().lock().lines().map_while(Result::ok) {
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut answers = VecDeque::new();
+    let mut input_closed = false;
+    let mut shown: Option<Prompt> = None;
+    let mut last_frame = Instant::now();
+    profile.begin_session();
+
+    loop {
+        loop {
+            match lines.try_recv() {
+                Ok(line) if !line.trim().is_empty() => answers.push_back(line),
+                Ok(_) => {}
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    input_closed = true;
+                    break;
+                }
+            }
+        }
+
+        let now = Instant::now();
+        trainer.tick(now - last_frame);
+        audio.advance(now - last_frame);
+        last_frame = now;
+
+        for event in audio.take_events() {
+            trainer.handle(event.into());
+        }
+
+        if trainer.is_streaming() {
+            for line in answers.drain(..) {
+                line.trim().chars().for_each(|character| trainer.handle(SessionEvent::Typed(character)));
+            }
+            if input_closed {
+                trainer.handle(SessionEvent::Stop);
+            }
+        } else if trainer.state() == PlayState::WaitingForAnswer {
+            if let Some(answer) = answers.pop_front() {
+                trainer.handle(SessionEvent::AnswerSubmitted(answer));
+            } else if input_closed {
+                trainer.handle(SessionEvent::Stop);
+            }
+        }
+
+        for effect in trainer.take_effects() {
+            audio.apply(&effect);
+
+            match effect {
+                Effect::Play(_) => {
+                    // Der Fehler erscheint mit dem naechsten Hinweis der Sitzung
+                    if let Some(error) = audio.last_error() {
+                        trainer.handle(SessionEvent::PlaybackFailed(error.to_string()));
+                    }
+                }
+                Effect::Graded(grade) => {
+                    profile.record(&grade);
+                    trainer.mode_mut().learn(&profile.data.confusion);
+                }
+                _ => {}
+            }
+        }
+
+        if shown.as_ref() != Some(trainer.prompt()) {
+            println!("{}", trainer.prompt());
+            shown = Some(trainer.prompt().clone());
+        }
+
+        if trainer.state() == PlayState::Stopped {
+            break;
+        }
+
+        thread::sleep(FRAME);
+    }
+
+    let seconds = trainer.training_time().as_secs_f32();
+    profile.add_practice_time(seconds);
+    let summary = profile.finish_session(seconds);
+    profile.save();
+    println!("{}", tr!("score", correct = trainer.correct_count(), wrong = trainer.wrong_count()));
+    println!("\n{}\n{}", tr!("summary-title"), summary);
+}
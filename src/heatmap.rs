@@ -0,0 +1,146 @@
+use bevy::prelude::*;
+use cw_engine::training_mode::label;
+use cw_engine::tr;
+
+use crate::screen::{despawn_screen, pressed, screen_root, spawn_button, Screen, PRIMARY};
+use crate::ActiveProfile;
+
+const MAX_ROWS: usize = 12;
+const MAX_COLUMNS: usize = 14;
+
+#[derive(Component)]
+struct HeatmapScreen;
+
+#[derive(Component)]
+struct CloseHeatmapButton;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Heatmap), setup_heatmap)
+        .add_systems(OnExit(Screen::Heatmap), despawn_screen::<HeatmapScreen>)
+        .add_systems(Update, heatmap_system.run_if(in_state(Screen::Heatmap)));
+}
+
+fn setup_heatmap(mut commands: Commands, profile: Res<ActiveProfile>) {
+    let matrix = &profile.data.confusion;
+    let rows = matrix.rows(MAX_ROWS);
+    let columns = matrix.columns(&rows, MAX_COLUMNS);
+
+    commands.spawn(screen_root(HeatmapScreen)).with_children(|panel| {
+        panel.spawn((
+            Text::new(tr!("heatmap-title", profile = profile.name.as_str())),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        if rows.is_empty() {
+            panel.spawn((
+                Text::new(tr!("heatmap-empty")),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+        } else {
+            panel.spawn(Node {
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(1.0),
+                ..default()
+            })
+            .with_children(|grid| {
+                grid.spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(1.0),
+                    ..default()
+                })
+                .with_children(|header| {
+                    spawn_cell(header, "", Color::NONE);
+                    for column in &columns {
+                        spawn_cell(header, &label(column), Color::NONE);
+                    }
+                });
+
+                for row in &rows {
+                    let total = matrix.total(row).max(1) as f32;
+
+                    grid.spawn(Node {
+                        flex_direction: FlexDirection::Row,
+                        column_gap: Val::Px(1.0),
+                        ..default()
+                    })
+                    .with_children(|line| {
+                        spawn_cell(line, &label(row), Color::NONE);
+                        for column in &columns {
+                            let count = matrix.count(row, column);
+                            let text = if count > 0 { count.to_string() } else { String::new() };
+                            spawn_cell(line, &text, heat_color(count as f32 / total, *row == column.as_str()));
+                        }
+                    });
+                }
+            });
+
+            let top: Vec<String> = matrix
+                .confusions()
+                .iter()
+                .take(5)
+                .map(|(expected, typed, count)| format!("{} -> {} ({}x)", label(expected), label(typed), count))
+                .collect();
+
+            panel.spawn((
+                Text::new(tr!("heatmap-top", confusions = top.join(", "))),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+        }
+
+        spawn_button(panel, &tr!("button-back"), Vec2::new(150.0, 36.0), PRIMARY, CloseHeatmapButton);
+    });
+}
+
+fn spawn_cell(parent: &mut ChildSpawnerCommands, text: &str, color: Color) {
+    parent.spawn((
+        Node {
+            width: Val::Px(36.0),
+            height: Val::Px(20.0),
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(color),
+    ))
+    .with_children(|cell| {
+        cell.spawn((
+            Text::new(text),
+            TextFont {
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+    });
+}
+
+fn heat_color(fraction: f32, diagonal: bool) -> Color {
+    if fraction <= 0.0 {
+        Color::srgb(0.15, 0.15, 0.15)
+    } else if diagonal {
+        Color::srgb(0.15, 0.15 + 0.5 * fraction, 0.15)
+    } else {
+        Color::srgb(0.25 + 0.7 * fraction, 0.15, 0.15)
+    }
+}
+
+fn heatmap_system(
+    interaction_query: Query<(&Interaction, &CloseHeatmapButton), Changed<Interaction>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if pressed(&interaction_query).next().is_some() {
+        next_screen.set(Screen::Home);
+    }
+}
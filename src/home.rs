@@ -0,0 +1,109 @@
+use bevy::prelude::*;
+use cw_engine::config::KeyAction;
+use cw_engine::tr;
+
+use crate::screen::{despawn_screen, pressed, screen_root, spawn_button, Screen, PRIMARY, SECONDARY};
+use crate::key_bindings::Hotkeys;
+use crate::{ActiveProfile, Trainer};
+
+/// Startseite, solange keine Sitzung laeuft.
+#[derive(Component)]
+struct HomeScreen;
+
+#[derive(Component, Clone, Copy)]
+enum HomeAction {
+    Start,
+    Profile,
+    Characters,
+    Stats,
+    Heatmap,
+    Settings,
+}
+
+impl HomeAction {
+    fn target(self) -> Screen {
+        match self {
+            HomeAction::Start => Screen::Training,
+            HomeAction::Profile => Screen::ProfileSelect,
+            HomeAction::Characters => Screen::CharacterSelect,
+            HomeAction::Stats => Screen::Stats,
+            HomeAction::Heatmap => Screen::Heatmap,
+            HomeAction::Settings => Screen::Settings,
+        }
+    }
+}
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Home), setup_home)
+        .add_systems(OnExit(Screen::Home), despawn_screen::<HomeScreen>)
+        .add_systems(Update, home_system.run_if(in_state(Screen::Home)));
+}
+
+fn setup_home(mut commands: Commands, trainer: Res<Trainer>, profile: Res<ActiveProfile>) {
+    let button = Vec2::new(150.0, 50.0);
+
+    commands.spawn(screen_root(HomeScreen)).with_children(|panel| {
+        panel.spawn((
+            Node {
+                margin: UiRect::top(Val::Px(40.0)),
+                ..default()
+            },
+            Text::new(tr!("home-title")),
+            TextFont {
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        panel.spawn((
+            Text::new(format!("{}\n{}", tr!("home-profile", profile = profile.name.as_str()), trainer.limits())),
+            TextFont {
+                font_size: 18.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            TextLayout::new_with_justify(JustifyText::Center),
+        ));
+
+        panel
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(10.0),
+                margin: UiRect::top(Val::Px(20.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_button(row, &tr!("button-start"), button, PRIMARY, HomeAction::Start);
+                spawn_button(row, &tr!("button-characters"), button, SECONDARY, HomeAction::Characters);
+                spawn_button(row, &tr!("button-profile"), button, SECONDARY, HomeAction::Profile);
+            });
+
+        panel
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                column_gap: Val::Px(10.0),
+                ..default()
+            })
+            .with_children(|row| {
+                spawn_button(row, &tr!("button-stats"), button, SECONDARY, HomeAction::Stats);
+                spawn_button(row, &tr!("button-heatmap"), button, SECONDARY, HomeAction::Heatmap);
+                spawn_button(row, &tr!("button-settings"), button, SECONDARY, HomeAction::Settings);
+            });
+    });
+}
+
+fn home_system(
+    interaction_query: Query<(&Interaction, &HomeAction), Changed<Interaction>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    hotkeys: Res<Hotkeys>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    for action in pressed(&interaction_query) {
+        next_screen.set(action.target());
+    }
+
+    if hotkeys.just_pressed(&keys, KeyAction::StartStop) {
+        next_screen.set(HomeAction::Start.target());
+    }
+}
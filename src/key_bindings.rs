@@ -0,0 +1,258 @@
+use bevy::input::keyboard::KeyboardInput;
+use bevy::input::ButtonState;
+use bevy::prelude::*;
+use cw_engine::config::{BindableKey, KeyAction, KeysConfig};
+use cw_engine::tr;
+
+use crate::accessibility::LiveText;
+use crate::screen::{despawn_screen, pressed, screen_root, spawn_button, ButtonColors, Screen, PRIMARY};
+use crate::settings::Settings;
+
+/// Die Taste in Bevy; fehlt hier eine neue `BindableKey`, baut das Fenster nicht mehr.
+fn key_code(key: BindableKey) -> KeyCode {
+    match key {
+        BindableKey::F1 => KeyCode::F1,
+        BindableKey::F2 => KeyCode::F2,
+        BindableKey::F3 => KeyCode::F3,
+        BindableKey::F4 => KeyCode::F4,
+        BindableKey::F5 => KeyCode::F5,
+        BindableKey::F6 => KeyCode::F6,
+        BindableKey::F7 => KeyCode::F7,
+        BindableKey::F8 => KeyCode::F8,
+        BindableKey::F9 => KeyCode::F9,
+        BindableKey::F10 => KeyCode::F10,
+        BindableKey::F12 => KeyCode::F12,
+        BindableKey::Insert => KeyCode::Insert,
+        BindableKey::Delete => KeyCode::Delete,
+        BindableKey::Home => KeyCode::Home,
+        BindableKey::End => KeyCode::End,
+        BindableKey::PageUp => KeyCode::PageUp,
+        BindableKey::PageDown => KeyCode::PageDown,
+        BindableKey::Up => KeyCode::ArrowUp,
+        BindableKey::Down => KeyCode::ArrowDown,
+        BindableKey::Left => KeyCode::ArrowLeft,
+        BindableKey::Right => KeyCode::ArrowRight,
+    }
+}
+
+fn bindable_key(code: KeyCode) -> Option<BindableKey> {
+    BindableKey::ALL.into_iter().find(|key| key_code(*key) == code)
+}
+
+/// Die aktuelle Belegung, fuer alle Screens, die auf Tasten reagieren.
+#[derive(Resource)]
+pub struct Hotkeys(KeysConfig);
+
+impl Hotkeys {
+    pub fn new(keys: &KeysConfig) -> Self {
+        Self(keys.clone())
+    }
+
+    pub fn just_pressed(&self, keys: &ButtonInput<KeyCode>, action: KeyAction) -> bool {
+        self.0.key(action).is_some_and(|key| keys.just_pressed(key_code(key)))
+    }
+
+    /// Name der Taste fuer Hinweise, z.B. "F6".
+    pub fn name(&self, action: KeyAction) -> &str {
+        self.0.get(action)
+    }
+}
+
+#[derive(Component)]
+struct KeyBindingsScreen;
+
+#[derive(Component, Clone, Copy)]
+enum KeyBindingsAction {
+    Assign(KeyAction),
+    Back,
+}
+
+#[derive(Component)]
+struct KeyText(KeyAction);
+
+#[derive(Component)]
+struct KeyBindingsStatusText;
+
+/// Aktion, die gerade auf ihre neue Taste wartet.
+#[derive(Resource, Default)]
+struct Capturing(Option<KeyAction>);
+
+const KEY: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.3, 0.3, 0.3),
+    hovered: Color::srgb(0.4, 0.4, 0.4),
+};
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<Capturing>()
+        .add_systems(OnEnter(Screen::KeyBindings), setup_key_bindings)
+        .add_systems(OnExit(Screen::KeyBindings), despawn_screen::<KeyBindingsScreen>)
+        // Erst die Taste abholen, damit das Enter, das einen Button ausloest, nicht gleich belegt wird
+        .add_systems(
+            Update,
+            (capture_key_system, key_bindings_system, update_key_texts)
+                .chain()
+                .run_if(in_state(Screen::KeyBindings)),
+        );
+}
+
+fn setup_key_bindings(mut commands: Commands, hotkeys: Res<Hotkeys>, mut capturing: ResMut<Capturing>) {
+    capturing.0 = None;
+
+    commands.spawn(screen_root(KeyBindingsScreen)).with_children(|panel| {
+        panel.spawn((
+            Text::new(tr!("key-bindings-title")),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        for action in KeyAction::ALL {
+            panel
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    max_width: Val::Px(500.0),
+                    align_items: AlignItems::Center,
+                    column_gap: Val::Px(8.0),
+                    ..default()
+                })
+                .with_children(|row| {
+                    row.spawn((
+                        Text::new(action.label()),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        Node {
+                            flex_grow: 1.0,
+                            ..default()
+                        },
+                    ));
+
+                    row.spawn((
+                        Button,
+                        Node {
+                            width: Val::Px(164.0),
+                            height: Val::Px(34.0),
+                            justify_content: JustifyContent::Center,
+                            align_items: AlignItems::Center,
+                            ..default()
+                        },
+                        BackgroundColor(KEY.normal),
+                        KEY,
+                        KeyBindingsAction::Assign(action),
+                    ))
+                    .with_children(|button| {
+                        button.spawn((
+                            Text::new(hotkeys.name(action)),
+                            TextFont {
+                                font_size: 18.0,
+                                ..default()
+                            },
+                            TextColor(Color::WHITE),
+                            KeyText(action),
+                        ));
+                    });
+                });
+        }
+
+        panel.spawn((
+            Text::new(tr!("key-bindings-hint")),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            TextLayout::new_with_justify(JustifyText::Center),
+        ));
+
+        spawn_button(panel, &tr!("button-back"), Vec2::new(150.0, 34.0), PRIMARY, KeyBindingsAction::Back);
+
+        panel.spawn((
+            Text::new(""),
+            TextFont {
+                font_size: 14.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            KeyBindingsStatusText,
+            LiveText,
+        ));
+    });
+}
+
+/// Uebernimmt die naechste gedrueckte Taste und speichert die Belegung sofort.
+fn capture_key_system(
+    mut events: EventReader<KeyboardInput>,
+    mut capturing: ResMut<Capturing>,
+    mut settings: ResMut<Settings>,
+    mut hotkeys: ResMut<Hotkeys>,
+    mut status_query: Query<&mut Text, With<KeyBindingsStatusText>>,
+) {
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+        let Some(action) = capturing.0 else {
+            continue;
+        };
+
+        capturing.0 = None;
+        if event.key_code == KeyCode::Escape {
+            continue;
+        }
+
+        let status = match bindable_key(event.key_code) {
+            Some(key) => {
+                let mut config = settings.config.clone();
+                config.keys.assign(action, key);
+
+                match config.save(&settings.path) {
+                    Ok(()) => {
+                        *hotkeys = Hotkeys::new(&config.keys);
+                        settings.config = config;
+                        tr!("settings-saved", path = settings.path.display().to_string())
+                    }
+                    Err(err) => {
+                        eprintln!("{}", tr!("error", error = err.to_string()));
+                        tr!("settings-not-saved", error = err.to_string())
+                    }
+                }
+            }
+            None => tr!("key-not-allowed", key = format!("{:?}", event.key_code)),
+        };
+
+        for mut text in status_query.iter_mut() {
+            **text = status.clone();
+        }
+    }
+}
+
+fn key_bindings_system(
+    interaction_query: Query<(&Interaction, &KeyBindingsAction), Changed<Interaction>>,
+    mut capturing: ResMut<Capturing>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    for action in pressed(&interaction_query) {
+        match *action {
+            KeyBindingsAction::Assign(action) => capturing.0 = Some(action),
+            KeyBindingsAction::Back => next_screen.set(Screen::Settings),
+        }
+    }
+}
+
+fn update_key_texts(
+    capturing: Res<Capturing>,
+    hotkeys: Res<Hotkeys>,
+    mut text_query: Query<(&mut Text, &KeyText)>,
+) {
+    if !capturing.is_changed() && !hotkeys.is_changed() {
+        return;
+    }
+
+    for (mut text, KeyText(action)) in text_query.iter_mut() {
+        **text = if capturing.0 == Some(*action) { tr!("key-press") } else { hotkeys.name(*action).to_string() };
+    }
+}
@@ -0,0 +1,97 @@
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PrimaryWindow, WindowMode};
+use cw_engine::config::UiConfig;
+
+const LARGE_SCALE: f32 = 1.3;
+const COMPACT_SCALE: f32 = 0.8;
+
+/// Darstellung des Fensters. Alle Screens sind mit Flexbox in logischen Pixeln gebaut,
+/// groessere Schrift und Kompaktmodus skalieren sie ueber `UiScale`.
+#[derive(Resource, Clone, Copy, PartialEq, Debug)]
+pub struct Layout {
+    pub large_font: bool,
+    pub fullscreen: bool,
+    pub compact: bool,
+}
+
+impl Layout {
+    pub fn new(ui: &UiConfig) -> Self {
+        Self {
+            large_font: ui.large_font,
+            fullscreen: ui.fullscreen,
+            compact: ui.compact,
+        }
+    }
+
+    pub fn scale(&self) -> f32 {
+        let mut scale = 1.0;
+        if self.large_font {
+            scale *= LARGE_SCALE;
+        }
+        if self.compact {
+            scale *= COMPACT_SCALE;
+        }
+        scale
+    }
+
+    fn size(&self) -> Vec2 {
+        let size = if self.compact { Vec2::new(560.0, 320.0) } else { Vec2::new(800.0, 480.0) };
+        if self.large_font { size * LARGE_SCALE } else { size }
+    }
+
+    fn mode(&self) -> WindowMode {
+        if self.fullscreen {
+            WindowMode::BorderlessFullscreen(MonitorSelection::Current)
+        } else {
+            WindowMode::Windowed
+        }
+    }
+
+    pub fn window(&self) -> Window {
+        let size = self.size();
+        Window {
+            title: "CW Listen Trainer".to_string(),
+            resolution: (size.x, size.y).into(),
+            mode: self.mode(),
+            ..default()
+        }
+    }
+}
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(Update, (fullscreen_key_system, apply_layout).chain());
+}
+
+fn fullscreen_key_system(keys: Res<ButtonInput<KeyCode>>, mut layout: ResMut<Layout>) {
+    if keys.just_pressed(KeyCode::F11) {
+        layout.fullscreen = !layout.fullscreen;
+    }
+}
+
+/// Uebernimmt Aenderungen aus den Einstellungen oder von F11 ins laufende Fenster.
+fn apply_layout(
+    layout: Res<Layout>,
+    mut applied: Local<Option<Layout>>,
+    mut ui_scale: ResMut<UiScale>,
+    mut window_query: Query<&mut Window, With<PrimaryWindow>>,
+) {
+    if applied.as_ref() == Some(&*layout) {
+        return;
+    }
+
+    ui_scale.0 = layout.scale();
+
+    for mut window in window_query.iter_mut() {
+        window.mode = layout.mode();
+
+        // Die Fenstergroesse nur anfassen, wenn sich der Modus geaendert hat, nicht bei jedem Start
+        if let Some(previous) = *applied
+            && (previous.compact != layout.compact || previous.large_font != layout.large_font)
+        {
+            let size = layout.size();
+            window.resolution.set(size.x, size.y);
+        }
+    }
+
+    *applied = Some(*layout);
+}
@@ -0,0 +1,156 @@
+use bevy::asset::LoadState;
+use bevy::audio::{AudioSink, AudioSource};
+use bevy::prelude::*;
+use cw_engine::loopback::{Samples, VirtualPlayback};
+use cw_engine::tr;
+
+/// Wo die Wiedergabe des aktuellen Zeichens steht. Bevy bekommt den Sound erst, wenn er
+/// geladen ist und sich dekodieren laesst, sonst bricht die Wiedergabe das Programm ab.
+#[derive(Component, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Playback {
+    Loading,
+    /// An Bevy uebergeben, der Sink kommt im naechsten Frame
+    Queued,
+    Started,
+    Finished,
+}
+
+/// Der Sound einer Wiedergabe, solange noch kein `AudioPlayer` daran haengt.
+#[derive(Component)]
+pub struct PlaybackSource(pub Handle<AudioSource>);
+
+/// Angehalten; ein erst danach fertig geladener Sound beginnt ebenfalls angehalten.
+#[derive(Component)]
+pub struct PlaybackPaused;
+
+#[derive(Event, Clone, PartialEq, Debug)]
+pub enum PlaybackEvent {
+    Started,
+    Finished,
+    Failed(String),
+}
+
+/// Hier laeuft die Audio-Ausgabe, zwischen der Eingabe und dem Weiterschalten der Sitzung.
+#[derive(SystemSet, Clone, PartialEq, Eq, Hash, Debug)]
+pub struct PlaybackSet;
+
+/// Wohin die Sounds gehen. `Virtual` braucht keine Soundkarte: ein Sound gilt als gespielt,
+/// sobald so viel Zeit vergangen ist, wie seine Samples dauern.
+#[derive(Resource, Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PlaybackOutput {
+    #[default]
+    Device,
+    Virtual,
+}
+
+/// Alles, was ohne Soundkarte gespielt wurde, ein Eintrag pro Wiedergabe.
+/// Wird nur mitgeschnitten, wenn die Resource existiert.
+#[derive(Resource, Default)]
+pub struct CapturedAudio(pub Vec<Samples>);
+
+/// Wiedergabe in virtueller Zeit statt ueber einen Sink.
+#[derive(Component)]
+struct VirtualOutput(VirtualPlayback);
+
+/// Wiedergabe ueber die Audio-Ausgabe von Bevy, ohne Ausgabegeraet in virtueller Zeit.
+pub fn plugin(app: &mut App) {
+    app.init_resource::<PlaybackOutput>()
+        .add_systems(Update, (track_playback, advance_virtual_output).chain().in_set(PlaybackSet));
+}
+
+pub fn spawn_playback(commands: &mut Commands, handle: Handle<AudioSource>) {
+    commands.spawn((PlaybackSource(handle), Playback::Loading));
+}
+
+/// Schaltet jede Wiedergabe weiter und meldet Beginn, Ende und Fehler als `PlaybackEvent`.
+#[allow(clippy::type_complexity, clippy::too_many_arguments)]
+fn track_playback(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    audio_sources: Res<Assets<AudioSource>>,
+    mut playback_query: Query<(Entity, &PlaybackSource, &mut Playback, Option<&AudioSink>, Has<PlaybackPaused>)>,
+    mut events: EventWriter<PlaybackEvent>,
+    mut output: ResMut<PlaybackOutput>,
+    mut captured: Option<ResMut<CapturedAudio>>,
+) {
+    for (entity, source, mut playback, sink, paused) in playback_query.iter_mut() {
+        match *playback {
+            Playback::Loading => {
+                let samples = match audio_sources.get(&source.0) {
+                    Some(audio) => Samples::decode(&audio.bytes),
+                    None => match asset_server.get_load_state(&source.0) {
+                        Some(LoadState::Failed(err)) => Err(err.to_string()),
+                        _ => continue,
+                    },
+                };
+
+                match samples {
+                    Ok(samples) if *output == PlaybackOutput::Virtual => {
+                        if let Some(captured) = captured.as_mut() {
+                            captured.0.push(samples.empty_like());
+                        }
+                        commands.entity(entity).insert(VirtualOutput(VirtualPlayback::new(samples)));
+                        *playback = Playback::Started;
+                        events.write(PlaybackEvent::Started);
+                    }
+                    Ok(_) => {
+                        let settings = if paused { PlaybackSettings::ONCE.paused() } else { PlaybackSettings::ONCE };
+                        commands.entity(entity).insert((AudioPlayer::new(source.0.clone()), settings));
+                        *playback = Playback::Queued;
+                    }
+                    Err(error) => {
+                        eprintln!("{}", tr!("error", error = error.as_str()));
+                        commands.entity(entity).despawn();
+                        events.write(PlaybackEvent::Failed(error));
+                    }
+                }
+            }
+            Playback::Queued => {
+                // Ohne Ausgabegeraet legt Bevy keinen Sink an; ab jetzt laeuft alles in virtueller Zeit
+                if sink.is_none() {
+                    eprintln!("{}", tr!("error", error = tr!("error-no-audio-output")));
+                    *output = PlaybackOutput::Virtual;
+                    commands.entity(entity).remove::<(AudioPlayer, PlaybackSettings)>();
+                    *playback = Playback::Loading;
+                    continue;
+                }
+
+                *playback = Playback::Started;
+                events.write(PlaybackEvent::Started);
+            }
+            Playback::Started => {
+                if sink.is_some_and(|sink| sink.empty()) {
+                    *playback = Playback::Finished;
+                    events.write(PlaybackEvent::Finished);
+                }
+            }
+            Playback::Finished => {}
+        }
+    }
+}
+
+/// Spielt Wiedergaben ohne Sink mit der Zeit des Frames weiter und schneidet sie ggf. mit.
+fn advance_virtual_output(
+    mut playback_query: Query<(&mut Playback, &mut VirtualOutput, Has<PlaybackPaused>)>,
+    mut events: EventWriter<PlaybackEvent>,
+    mut captured: Option<ResMut<CapturedAudio>>,
+    time: Res<Time>,
+) {
+    for (mut playback, mut output, paused) in playback_query.iter_mut() {
+        if *playback != Playback::Started || paused {
+            continue;
+        }
+
+        let played = output.0.advance(time.delta());
+        if let Some(captured) = captured.as_mut()
+            && let Some(last) = captured.0.last_mut()
+        {
+            last.data.extend_from_slice(played);
+        }
+
+        if output.0.is_finished() {
+            *playback = Playback::Finished;
+            events.write(PlaybackEvent::Finished);
+        }
+    }
+}
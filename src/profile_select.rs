@@ -0,0 +1,190 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use cw_engine::{tr, Profile};
+
+use crate::screen::{despawn_screen, pressed, screen_root, spawn_button, Screen, CONFIRM, PRIMARY, SECONDARY};
+use crate::{ActiveProfile, Trainer};
+
+const MAX_NAME_LEN: usize = 32;
+
+#[derive(Component)]
+struct ProfileSelectScreen;
+
+#[derive(Component)]
+enum ProfileAction {
+    Select(String),
+    Create,
+    Back,
+}
+
+/// Eingabefeld fuer den Namen eines neuen Profils; der Text ist zugleich der Name.
+#[derive(Component)]
+struct NewProfileName;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::ProfileSelect), setup_profile_select)
+        .add_systems(OnExit(Screen::ProfileSelect), despawn_screen::<ProfileSelectScreen>)
+        .add_systems(
+            Update,
+            (name_input_system, profile_select_system).chain().run_if(in_state(Screen::ProfileSelect)),
+        );
+}
+
+fn setup_profile_select(mut commands: Commands, profile: Res<ActiveProfile>) {
+    let mut names = Profile::list();
+    // Ein frisch angelegtes Profil liegt erst nach der ersten Sitzung auf der Platte
+    if !names.contains(&profile.name) {
+        names.push(profile.name.clone());
+        names.sort();
+    }
+
+    commands.spawn(screen_root(ProfileSelectScreen)).with_children(|panel| {
+        panel.spawn((
+            Text::new(tr!("profile-title")),
+            TextFont {
+                font_size: 22.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        panel
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                flex_wrap: FlexWrap::Wrap,
+                justify_content: JustifyContent::Center,
+                column_gap: Val::Px(8.0),
+                row_gap: Val::Px(8.0),
+                ..default()
+            })
+            .with_children(|list| {
+                for name in names {
+                    let colors = if name == profile.name { CONFIRM } else { SECONDARY };
+                    spawn_button(list, &name, Vec2::new(150.0, 34.0), colors, ProfileAction::Select(name.clone()));
+                }
+            });
+
+        panel
+            .spawn(Node {
+                flex_direction: FlexDirection::Row,
+                align_items: AlignItems::Center,
+                column_gap: Val::Px(10.0),
+                margin: UiRect::top(Val::Px(10.0)),
+                ..default()
+            })
+            .with_children(|row| {
+                row.spawn((
+                    Text::new(tr!("profile-new")),
+                    TextFont {
+                        font_size: 18.0,
+                        ..default()
+                    },
+                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ));
+
+                row.spawn((
+                    Node {
+                        width: Val::Px(250.0),
+                        height: Val::Px(34.0),
+                        border: UiRect::all(Val::Px(2.0)),
+                        padding: UiRect::horizontal(Val::Px(6.0)),
+                        align_items: AlignItems::Center,
+                        ..default()
+                    },
+                    BorderColor(Color::srgb(0.5, 0.5, 0.5)),
+                    BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                ))
+                .with_children(|field| {
+                    field.spawn((
+                        Text::new(""),
+                        TextFont {
+                            font_size: 18.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        NewProfileName,
+                    ));
+                });
+
+                spawn_button(row, &tr!("button-create"), Vec2::new(150.0, 34.0), CONFIRM, ProfileAction::Create);
+            });
+
+        spawn_button(panel, &tr!("button-back"), Vec2::new(150.0, 34.0), PRIMARY, ProfileAction::Back);
+    });
+}
+
+/// Nimmt den Namen fuer ein neues Profil entgegen; erlaubt sind nur Zeichen, die auch als Dateiname taugen.
+fn name_input_system(
+    mut events: EventReader<KeyboardInput>,
+    mut name_query: Query<&mut Text, With<NewProfileName>>,
+) {
+    for event in events.read() {
+        if event.state != ButtonState::Pressed {
+            continue;
+        }
+
+        for mut name in name_query.iter_mut() {
+            match &event.logical_key {
+                Key::Backspace => {
+                    name.pop();
+                }
+                Key::Character(text) => {
+                    for ch in text.chars() {
+                        if (ch.is_ascii_alphanumeric() || ch == '-' || ch == '_') && name.len() < MAX_NAME_LEN {
+                            name.push(ch);
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn profile_select_system(
+    interaction_query: Query<(&Interaction, &ProfileAction), Changed<Interaction>>,
+    name_query: Query<&Text, With<NewProfileName>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    button_query: Query<(), With<Button>>,
+    mut profile: ResMut<ActiveProfile>,
+    mut trainer: ResMut<Trainer>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    let new_name = name_query.iter().next().map(|text| text.0.clone()).unwrap_or_default();
+    let mut chosen = None;
+
+    for action in pressed(&interaction_query) {
+        match action {
+            ProfileAction::Select(name) => chosen = Some(name.clone()),
+            ProfileAction::Create if !new_name.is_empty() => chosen = Some(new_name.clone()),
+            ProfileAction::Create => {}
+            ProfileAction::Back => next_screen.set(Screen::Home),
+        }
+    }
+
+    // Steht der Fokus auf einem Button, loest Enter diesen aus
+    let button_focused = focus.0.is_some_and(|entity| button_query.contains(entity));
+    if keys.just_pressed(KeyCode::Enter) && !button_focused && !new_name.is_empty() {
+        chosen = Some(new_name);
+    }
+
+    let Some(name) = chosen else {
+        return;
+    };
+
+    if name != profile.name {
+        profile.save();
+        let new_profile = Profile::load(&name);
+        trainer.mode_mut().learn(&new_profile.data.confusion);
+        // Sofort speichern, damit das Profil in der Liste auftaucht
+        new_profile.save();
+        profile.0 = new_profile;
+        println!("{}", tr!("home-profile", profile = name));
+    }
+
+    next_screen.set(Screen::Home);
+}
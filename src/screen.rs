@@ -0,0 +1,115 @@
+use bevy::prelude::*;
+
+/// Die Bildschirme der Anwendung; jeder baut beim Betreten seine Oberflaeche auf und raeumt sie beim Verlassen ab.
+#[derive(States, Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+pub enum Screen {
+    #[default]
+    Home,
+    ProfileSelect,
+    CharacterSelect,
+    Settings,
+    KeyBindings,
+    Training,
+    Summary,
+    Stats,
+    Heatmap,
+}
+
+/// Normale und hervorgehobene Farbe eines Buttons; die Aktion haengt an einer eigenen Komponente des Screens.
+#[derive(Component, Clone, Copy)]
+pub struct ButtonColors {
+    pub normal: Color,
+    pub hovered: Color,
+}
+
+pub const PRIMARY: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.2, 0.5, 0.8),
+    hovered: Color::srgb(0.3, 0.6, 0.9),
+};
+pub const SECONDARY: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.5, 0.4, 0.7),
+    hovered: Color::srgb(0.6, 0.5, 0.8),
+};
+pub const CONFIRM: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.3, 0.7, 0.3),
+    hovered: Color::srgb(0.35, 0.8, 0.35),
+};
+pub const DANGER: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.8, 0.2, 0.2),
+    hovered: Color::srgb(0.9, 0.3, 0.3),
+};
+
+pub fn plugin(app: &mut App) {
+    app.init_state::<Screen>().add_systems(Update, button_color_system);
+}
+
+/// Entfernt beim Verlassen eines Screens alles, was mit seinem Marker gespawnt wurde.
+pub fn despawn_screen<T: Component>(mut commands: Commands, query: Query<Entity, With<T>>) {
+    for entity in query.iter() {
+        commands.entity(entity).despawn();
+    }
+}
+
+/// Wurzel eines Screens ueber das ganze Fenster.
+pub fn screen_root(marker: impl Component) -> impl Bundle {
+    (
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Center,
+            padding: UiRect::all(Val::Px(10.0)),
+            row_gap: Val::Px(8.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.08, 0.08, 0.08)),
+        marker,
+    )
+}
+
+pub fn spawn_button(parent: &mut ChildSpawnerCommands, text: &str, size: Vec2, colors: ButtonColors, action: impl Component) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(size.x),
+                height: Val::Px(size.y),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(colors.normal),
+            colors,
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+fn button_color_system(mut query: Query<(&Interaction, &ButtonColors, &mut BackgroundColor), Changed<Interaction>>) {
+    for (interaction, colors, mut background) in query.iter_mut() {
+        *background = match interaction {
+            Interaction::Hovered => colors.hovered,
+            Interaction::Pressed | Interaction::None => colors.normal,
+        }
+        .into();
+    }
+}
+
+/// Liefert gedrueckte Buttons mit ihrer Aktion, fuer die Systeme der einzelnen Screens.
+pub fn pressed<'a, A: Component>(
+    query: &'a Query<(&Interaction, &A), Changed<Interaction>>,
+) -> impl Iterator<Item = &'a A> {
+    query
+        .iter()
+        .filter(|(interaction, _)| **interaction == Interaction::Pressed)
+        .map(|(_, action)| action)
+}
@@ -0,0 +1,520 @@
+use std::{ops::RangeInclusive, path::PathBuf};
+
+use bevy::prelude::*;
+use cw_engine::config::{
+    Config, GAP_MS, LAG, PAUSE_MS, REPEATS, REPEAT_PAUSE_MS, SESSION_ITEMS, SESSION_MINUTES,
+    REPLAY_PENALTY, TARGET_ACCURACY, WORDS_PER_CHUNK,
+};
+use cw_engine::accessibility::AnswerBack;
+use cw_engine::text::UnknownPolicy;
+use cw_engine::{tr, Language};
+
+use crate::screen::{despawn_screen, pressed, spawn_button, ButtonColors, Screen, CONFIRM, PRIMARY, SECONDARY};
+use crate::accessibility::{Feedback, LiveText};
+use crate::cli::Overrides;
+use crate::layout::Layout;
+use crate::Trainer;
+
+/// Die geladene `config.toml` und der im Einstellungsfenster bearbeitete Entwurf.
+#[derive(Resource)]
+pub struct Settings {
+    pub path: PathBuf,
+    pub config: Config,
+    draft: Config,
+    overrides: Overrides,
+}
+
+impl Settings {
+    pub fn new(path: PathBuf, config: Config, overrides: Overrides) -> Self {
+        Self {
+            path,
+            draft: config.clone(),
+            config,
+            overrides,
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Setting {
+    Pause,
+    RepeatPause,
+    Repeats,
+    Lag,
+    Gap,
+    WordsPerChunk,
+    SessionMinutes,
+    SessionItems,
+    TargetAccuracy,
+    ReplayPenalty,
+}
+
+impl Setting {
+    const ALL: [Setting; 10] = [
+        Setting::Pause,
+        Setting::RepeatPause,
+        Setting::Repeats,
+        Setting::Lag,
+        Setting::Gap,
+        Setting::WordsPerChunk,
+        Setting::SessionMinutes,
+        Setting::SessionItems,
+        Setting::TargetAccuracy,
+        Setting::ReplayPenalty,
+    ];
+
+    fn label(self) -> String {
+        tr!(match self {
+            Setting::Pause => "setting-pause",
+            Setting::RepeatPause => "setting-repeat-pause",
+            Setting::Repeats => "setting-repeats",
+            Setting::Lag => "setting-lag",
+            Setting::Gap => "setting-gap",
+            Setting::WordsPerChunk => "setting-words-per-chunk",
+            Setting::SessionMinutes => "setting-session-minutes",
+            Setting::SessionItems => "setting-session-items",
+            Setting::TargetAccuracy => "setting-target-accuracy",
+            Setting::ReplayPenalty => "setting-replay-penalty",
+        })
+    }
+
+    fn step(self) -> u64 {
+        match self {
+            Setting::Pause | Setting::RepeatPause | Setting::Gap => 100,
+            Setting::Repeats | Setting::Lag | Setting::WordsPerChunk => 1,
+            Setting::SessionMinutes | Setting::TargetAccuracy => 5,
+            Setting::SessionItems | Setting::ReplayPenalty => 10,
+        }
+    }
+
+    fn range(self) -> RangeInclusive<u64> {
+        match self {
+            Setting::Pause => PAUSE_MS,
+            Setting::RepeatPause => REPEAT_PAUSE_MS,
+            Setting::Repeats => *REPEATS.start() as u64..=*REPEATS.end() as u64,
+            Setting::Lag => LAG,
+            Setting::Gap => GAP_MS,
+            Setting::WordsPerChunk => WORDS_PER_CHUNK,
+            Setting::SessionMinutes => SESSION_MINUTES,
+            Setting::SessionItems => SESSION_ITEMS,
+            Setting::TargetAccuracy => TARGET_ACCURACY,
+            Setting::ReplayPenalty => REPLAY_PENALTY,
+        }
+    }
+
+    fn value(self, config: &Config) -> u64 {
+        match self {
+            Setting::Pause => config.timings.pause_ms,
+            Setting::RepeatPause => config.timings.repeat_pause_ms,
+            Setting::Repeats => config.timings.repeats as u64,
+            Setting::Lag => config.copy_behind.lag,
+            Setting::Gap => config.copy_behind.gap_ms,
+            Setting::WordsPerChunk => config.text.words_per_chunk,
+            Setting::SessionMinutes => config.session.minutes,
+            Setting::SessionItems => config.session.items,
+            Setting::TargetAccuracy => config.session.target_accuracy,
+            Setting::ReplayPenalty => config.session.replay_penalty,
+        }
+    }
+
+    fn set(self, config: &mut Config, value: u64) {
+        match self {
+            Setting::Pause => config.timings.pause_ms = value,
+            Setting::RepeatPause => config.timings.repeat_pause_ms = value,
+            Setting::Repeats => config.timings.repeats = value as u32,
+            Setting::Lag => config.copy_behind.lag = value,
+            Setting::Gap => config.copy_behind.gap_ms = value,
+            Setting::WordsPerChunk => config.text.words_per_chunk = value,
+            Setting::SessionMinutes => config.session.minutes = value,
+            Setting::SessionItems => config.session.items = value,
+            Setting::TargetAccuracy => config.session.target_accuracy = value,
+            Setting::ReplayPenalty => config.session.replay_penalty = value,
+        }
+    }
+
+    fn format(self, value: u64) -> String {
+        match self {
+            Setting::Repeats => format!("{}x", value),
+            Setting::Lag | Setting::WordsPerChunk => value.to_string(),
+            // 0 heisst ohne Grenze
+            Setting::SessionMinutes | Setting::SessionItems | Setting::TargetAccuracy | Setting::ReplayPenalty if value == 0 => tr!("off"),
+            Setting::SessionMinutes => format!("{} min", value),
+            Setting::SessionItems => value.to_string(),
+            Setting::TargetAccuracy | Setting::ReplayPenalty => format!("{} %", value),
+            _ => format!("{} ms", value),
+        }
+    }
+}
+
+#[derive(Component)]
+struct SettingsScreen;
+
+#[derive(Component)]
+enum SettingsAction {
+    Back,
+    Keys,
+    Save,
+    Step { setting: Setting, up: bool },
+    Next(Choice),
+}
+
+#[derive(Component)]
+struct SettingValueText(Setting);
+
+#[derive(Component)]
+struct SettingsStatusText;
+
+/// Einstellungen, bei denen ein Klick zum naechsten Wert weiterschaltet.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Choice {
+    Unknown,
+    CopyCheck,
+    Language,
+    LargeFont,
+    Fullscreen,
+    Compact,
+    Accessible,
+    AnswerBack,
+}
+
+impl Choice {
+    const ALL: [Choice; 8] = [
+        Choice::Unknown,
+        Choice::CopyCheck,
+        Choice::Language,
+        Choice::LargeFont,
+        Choice::Fullscreen,
+        Choice::Compact,
+        Choice::Accessible,
+        Choice::AnswerBack,
+    ];
+
+    fn label(self) -> String {
+        tr!(match self {
+            Choice::Unknown => "setting-unknown",
+            Choice::CopyCheck => "setting-copy-check",
+            Choice::Language => "setting-language",
+            Choice::LargeFont => "setting-large-font",
+            Choice::Fullscreen => "setting-fullscreen",
+            Choice::Compact => "setting-compact",
+            Choice::Accessible => "setting-accessible",
+            Choice::AnswerBack => "setting-answer-back",
+        })
+    }
+
+    fn next(self, config: &mut Config) {
+        match self {
+            Choice::Unknown => {
+                let index = UnknownPolicy::ALL.iter().position(|policy| *policy == config.text.unknown).unwrap_or(0);
+                config.text.unknown = UnknownPolicy::ALL[(index + 1) % UnknownPolicy::ALL.len()];
+            }
+            Choice::CopyCheck => config.text.copy_check = !config.text.copy_check,
+            Choice::Language => {
+                config.ui.language = match config.ui.language {
+                    None => Some(Language::De),
+                    Some(Language::De) => Some(Language::En),
+                    Some(Language::En) => None,
+                };
+            }
+            Choice::LargeFont => config.ui.large_font = !config.ui.large_font,
+            Choice::Fullscreen => config.ui.fullscreen = !config.ui.fullscreen,
+            Choice::Compact => config.ui.compact = !config.ui.compact,
+            Choice::Accessible => config.accessibility.enabled = !config.accessibility.enabled,
+            Choice::AnswerBack => {
+                let all = AnswerBack::ALL;
+                let index = all.iter().position(|answer_back| *answer_back == config.accessibility.answer_back).unwrap_or(0);
+                config.accessibility.answer_back = all[(index + 1) % all.len()];
+            }
+        }
+    }
+
+    fn format(self, config: &Config) -> String {
+        match self {
+            Choice::Unknown => config.text.unknown.label(),
+            Choice::CopyCheck => tr!(if config.text.copy_check { "on" } else { "off" }),
+            Choice::Language => config
+                .ui
+                .language
+                .map_or_else(|| tr!("language-system"), |language| language.name().to_string()),
+            Choice::LargeFont => tr!(if config.ui.large_font { "on" } else { "off" }),
+            Choice::Fullscreen => tr!(if config.ui.fullscreen { "on" } else { "off" }),
+            Choice::Compact => tr!(if config.ui.compact { "on" } else { "off" }),
+            Choice::Accessible => tr!(if config.accessibility.enabled { "on" } else { "off" }),
+            Choice::AnswerBack => config.accessibility.answer_back.label(),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ChoiceText(Choice);
+
+/// Graue Buttons fuer +/- und die Auswahlfelder.
+const STEP: ButtonColors = ButtonColors {
+    normal: Color::srgb(0.3, 0.3, 0.3),
+    hovered: Color::srgb(0.4, 0.4, 0.4),
+};
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Settings), setup_settings)
+        .add_systems(OnExit(Screen::Settings), despawn_screen::<SettingsScreen>)
+        .add_systems(
+            Update,
+            (settings_system, update_settings).chain().run_if(in_state(Screen::Settings)),
+        );
+}
+
+/// Jedes Oeffnen beginnt mit den gespeicherten Werten.
+fn setup_settings(mut commands: Commands, mut settings: ResMut<Settings>) {
+    settings.draft = settings.config.clone();
+    let draft = &settings.draft;
+
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                align_items: AlignItems::Center,
+                padding: UiRect::all(Val::Px(10.0)),
+                row_gap: Val::Px(8.0),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.08, 0.08, 0.08)),
+            SettingsScreen,
+        ))
+        .with_children(|panel| {
+            panel.spawn((
+                Text::new(tr!("settings-title")),
+                TextFont {
+                    font_size: 22.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+
+            // Zwei Spalten, die mit dem Fenster mitwachsen
+            panel
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_wrap: FlexWrap::Wrap,
+                    row_gap: Val::Px(4.0),
+                    ..default()
+                })
+                .with_children(|grid| {
+                    for setting in Setting::ALL {
+                        grid.spawn(row_node())
+                            .with_children(|row| {
+                                row.spawn((
+                                    Text::new(setting.label()),
+                                    TextFont {
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                    Node {
+                                        flex_grow: 1.0,
+                                        flex_basis: Val::Px(0.0),
+                                        ..default()
+                                    },
+                                ));
+
+                                spawn_step_button(row, setting, false);
+
+                                row.spawn((
+                                    Text::new(setting.format(setting.value(draft))),
+                                    TextFont {
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::WHITE),
+                                    TextLayout::new_with_justify(JustifyText::Center),
+                                    Node {
+                                        width: Val::Px(80.0),
+                                        ..default()
+                                    },
+                                    SettingValueText(setting),
+                                ));
+
+                                spawn_step_button(row, setting, true);
+                            });
+                    }
+
+                    for choice in Choice::ALL {
+                        grid.spawn(row_node())
+                            .with_children(|row| {
+                                row.spawn((
+                                    Text::new(choice.label()),
+                                    TextFont {
+                                        font_size: 16.0,
+                                        ..default()
+                                    },
+                                    TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                                    Node {
+                                        flex_grow: 1.0,
+                                        flex_basis: Val::Px(0.0),
+                                        ..default()
+                                    },
+                                ));
+
+                                row.spawn((
+                                    Button,
+                                    Node {
+                                        width: Val::Px(164.0),
+                                        height: Val::Px(30.0),
+                                        justify_content: JustifyContent::Center,
+                                        align_items: AlignItems::Center,
+                                        ..default()
+                                    },
+                                    BackgroundColor(STEP.normal),
+                                    STEP,
+                                    SettingsAction::Next(choice),
+                                ))
+                                .with_children(|button| {
+                                    button.spawn((
+                                        Text::new(choice.format(draft)),
+                                        TextFont {
+                                            font_size: 16.0,
+                                            ..default()
+                                        },
+                                        TextColor(Color::WHITE),
+                                        ChoiceText(choice),
+                                    ));
+                                });
+                            });
+                    }
+                });
+
+            panel
+                .spawn(Node {
+                    flex_direction: FlexDirection::Row,
+                    column_gap: Val::Px(10.0),
+                    margin: UiRect::top(Val::Px(10.0)),
+                    ..default()
+                })
+                .with_children(|row| {
+                    spawn_button(row, &tr!("button-back"), Vec2::new(150.0, 34.0), PRIMARY, SettingsAction::Back);
+                    spawn_button(row, &tr!("button-keys"), Vec2::new(150.0, 34.0), SECONDARY, SettingsAction::Keys);
+                    spawn_button(row, &tr!("button-save"), Vec2::new(150.0, 34.0), CONFIRM, SettingsAction::Save);
+                });
+
+            panel.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                SettingsStatusText,
+                LiveText,
+            ));
+        });
+}
+
+fn row_node() -> Node {
+    Node {
+        width: Val::Percent(50.0),
+        flex_grow: 1.0,
+        align_items: AlignItems::Center,
+        column_gap: Val::Px(8.0),
+        padding: UiRect::horizontal(Val::Px(8.0)),
+        ..default()
+    }
+}
+
+fn spawn_step_button(parent: &mut ChildSpawnerCommands, setting: Setting, up: bool) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(34.0),
+                height: Val::Px(30.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(STEP.normal),
+            STEP,
+            SettingsAction::Step { setting, up },
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(if up { "+" } else { "-" }),
+                TextFont {
+                    font_size: 20.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        });
+}
+
+fn settings_system(
+    interaction_query: Query<(&Interaction, &SettingsAction), Changed<Interaction>>,
+    mut status_query: Query<&mut Text, With<SettingsStatusText>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    mut settings: ResMut<Settings>,
+    mut trainer: ResMut<Trainer>,
+    mut layout: ResMut<Layout>,
+    mut feedback: ResMut<Feedback>,
+) {
+    for action in pressed(&interaction_query) {
+        match *action {
+            SettingsAction::Back => next_screen.set(Screen::Home),
+            SettingsAction::Keys => next_screen.set(Screen::KeyBindings),
+            SettingsAction::Save => {
+                let status = match settings.draft.save(&settings.path) {
+                    Ok(()) => {
+                        settings.config = settings.draft.clone();
+                        trainer.set_timings(settings.config.timings.timings());
+                        trainer.set_copy_behind(settings.config.copy_behind.copy_behind());
+                        // Angaben auf der Kommandozeile gelten weiter
+                        trainer.set_limits(settings.overrides.limits(&settings.config));
+                        trainer.set_replay_credit(settings.config.session.replay_credit());
+                        *layout = settings.overrides.layout(&settings.config);
+                        *feedback = settings.overrides.feedback(&settings.config);
+                        tr!("settings-saved", path = settings.path.display().to_string())
+                    }
+                    Err(err) => {
+                        eprintln!("{}", tr!("error", error = err.to_string()));
+                        tr!("settings-not-saved", error = err.to_string())
+                    }
+                };
+
+                for mut text in status_query.iter_mut() {
+                    **text = status.clone();
+                }
+            }
+            SettingsAction::Step { setting, up } => {
+                let range = setting.range();
+                let value = setting.value(&settings.draft);
+                let value = if up {
+                    value.saturating_add(setting.step())
+                } else {
+                    value.saturating_sub(setting.step())
+                };
+
+                setting.set(&mut settings.draft, value.clamp(*range.start(), *range.end()));
+            }
+            SettingsAction::Next(choice) => choice.next(&mut settings.draft),
+        }
+    }
+}
+
+/// Zieht die Anzeige nach, sobald sich der Entwurf aendert.
+fn update_settings(
+    mut value_query: Query<(&mut Text, &SettingValueText), Without<ChoiceText>>,
+    mut choice_query: Query<(&mut Text, &ChoiceText)>,
+    settings: Res<Settings>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+
+    for (mut text, SettingValueText(setting)) in value_query.iter_mut() {
+        **text = setting.format(setting.value(&settings.draft));
+    }
+
+    for (mut text, ChoiceText(choice)) in choice_query.iter_mut() {
+        **text = choice.format(&settings.draft);
+    }
+}
@@ -0,0 +1,251 @@
+use bevy::prelude::*;
+use cw_engine::export::{export_dir, export_history};
+use cw_engine::stats::{daily_accuracy, daily_minutes, per_character, streaks};
+use cw_engine::training_mode::label;
+use cw_engine::tr;
+
+use crate::screen::{despawn_screen, pressed, screen_root, spawn_button, Screen, PRIMARY, SECONDARY};
+use crate::accessibility::LiveText;
+use crate::ActiveProfile;
+
+const CHART_DAYS: u64 = 14;
+const MAX_CHAR_BARS: usize = 24;
+
+#[derive(Component)]
+struct StatsScreen;
+
+#[derive(Component)]
+enum StatsAction {
+    Back,
+    Export,
+}
+
+#[derive(Component)]
+struct ExportStatusText;
+
+struct Bar {
+    label: String,
+    value: f32,
+    caption: String,
+}
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Stats), setup_stats)
+        .add_systems(OnExit(Screen::Stats), despawn_screen::<StatsScreen>)
+        .add_systems(Update, stats_system.run_if(in_state(Screen::Stats)));
+}
+
+fn setup_stats(mut commands: Commands, profile: Res<ActiveProfile>) {
+    let history = &profile.data.history;
+    let score: f32 = history.iter().map(|record| record.score()).sum();
+    let replays: u32 = history.iter().map(|record| record.replays).sum();
+    let (current_streak, best_streak) = streaks(history, &profile.data.practice_seconds);
+    let total_minutes: f32 = profile.data.practice_seconds.values().sum::<f32>() / 60.0;
+
+    let accuracy_bars: Vec<Bar> = daily_accuracy(history, CHART_DAYS)
+        .into_iter()
+        .map(|(date, accuracy)| Bar {
+            label: date.format("%d").to_string(),
+            value: accuracy.unwrap_or(0.0),
+            caption: accuracy.map_or(String::new(), |a| format!("{:.0}", a * 100.0)),
+        })
+        .collect();
+
+    let minute_values = daily_minutes(&profile.data.practice_seconds, CHART_DAYS);
+    let max_minutes = minute_values.iter().map(|(_, minutes)| *minutes).fold(1.0, f32::max);
+    let minute_bars: Vec<Bar> = minute_values
+        .into_iter()
+        .map(|(date, minutes)| Bar {
+            label: date.format("%d").to_string(),
+            value: minutes / max_minutes,
+            caption: if minutes > 0.0 { format!("{:.0}", minutes) } else { String::new() },
+        })
+        .collect();
+
+    let char_bars: Vec<Bar> = per_character(history)
+        .into_iter()
+        .take(MAX_CHAR_BARS)
+        .map(|stats| Bar {
+            label: label(&stats.name),
+            value: stats.accuracy(),
+            caption: format!("{:.1}s", stats.median_latency_ms as f32 / 1000.0),
+        })
+        .collect();
+
+    commands.spawn(screen_root(StatsScreen)).with_children(|panel| {
+        panel.spawn((
+            Text::new(tr!(
+                "stats-summary",
+                profile = profile.name.as_str(),
+                answers = history.len(),
+                accuracy = format!("{:.0}", score * 100.0 / history.len().max(1) as f32),
+                replays = replays,
+                minutes = format!("{:.0}", total_minutes),
+                streak = current_streak,
+                best = best_streak,
+            )),
+            TextFont {
+                font_size: 16.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        panel.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            column_gap: Val::Px(20.0),
+            ..default()
+        })
+        .with_children(|row| {
+            spawn_bar_chart(row, &tr!("stats-daily-accuracy"), &accuracy_bars, Color::srgb(0.3, 0.7, 0.3), 14.0);
+            spawn_bar_chart(row, &tr!("stats-daily-minutes"), &minute_bars, Color::srgb(0.2, 0.5, 0.8), 14.0);
+        });
+
+        spawn_bar_chart(
+            panel,
+            &tr!("stats-per-character"),
+            &char_bars,
+            Color::srgb(0.8, 0.5, 0.2),
+            24.0,
+        );
+
+        panel.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(10.0),
+            ..default()
+        })
+        .with_children(|row| {
+            spawn_button(row, &tr!("button-back"), Vec2::new(150.0, 32.0), PRIMARY, StatsAction::Back);
+            spawn_button(row, &tr!("button-export"), Vec2::new(150.0, 32.0), SECONDARY, StatsAction::Export);
+
+            row.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 11.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                ExportStatusText,
+                LiveText,
+            ));
+        });
+    });
+}
+
+fn spawn_bar_chart(parent: &mut ChildSpawnerCommands, title: &str, bars: &[Bar], color: Color, bar_width: f32) {
+    parent.spawn(Node {
+        flex_direction: FlexDirection::Column,
+        align_items: AlignItems::Center,
+        ..default()
+    })
+    .with_children(|chart| {
+        chart.spawn((
+            Text::new(title),
+            TextFont {
+                font_size: 13.0,
+                ..default()
+            },
+            TextColor(Color::srgb(0.8, 0.8, 0.8)),
+        ));
+
+        if bars.is_empty() {
+            chart.spawn((
+                Text::new(tr!("stats-no-data")),
+                TextFont {
+                    font_size: 13.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+            return;
+        }
+
+        chart.spawn(Node {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::FlexEnd,
+            column_gap: Val::Px(3.0),
+            ..default()
+        })
+        .with_children(|columns| {
+            for bar in bars {
+                columns.spawn(Node {
+                    flex_direction: FlexDirection::Column,
+                    align_items: AlignItems::Center,
+                    width: Val::Px(bar_width),
+                    ..default()
+                })
+                .with_children(|column| {
+                    column.spawn((
+                        Text::new(bar.caption.clone()),
+                        TextFont {
+                            font_size: 9.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    ));
+
+                    // Hintergrund in voller Hoehe, darin der eigentliche Balken
+                    column.spawn((
+                        Node {
+                            width: Val::Px(bar_width - 2.0),
+                            height: Val::Px(70.0),
+                            flex_direction: FlexDirection::Column,
+                            justify_content: JustifyContent::FlexEnd,
+                            ..default()
+                        },
+                        BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                    ))
+                    .with_children(|track| {
+                        track.spawn((
+                            Node {
+                                width: Val::Percent(100.0),
+                                height: Val::Percent(bar.value.clamp(0.0, 1.0) * 100.0),
+                                ..default()
+                            },
+                            BackgroundColor(color),
+                        ));
+                    });
+
+                    column.spawn((
+                        Text::new(bar.label.clone()),
+                        TextFont {
+                            font_size: 11.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                    ));
+                });
+            }
+        });
+    });
+}
+
+fn stats_system(
+    interaction_query: Query<(&Interaction, &StatsAction), Changed<Interaction>>,
+    mut status_query: Query<&mut Text, With<ExportStatusText>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+    profile: Res<ActiveProfile>,
+) {
+    for action in pressed(&interaction_query) {
+        match action {
+            StatsAction::Back => next_screen.set(Screen::Home),
+            StatsAction::Export => {
+                let status = match export_history(&profile, &export_dir()) {
+                    Ok((csv_path, json_path)) => {
+                        tr!("export-saved", csv = csv_path.display().to_string(), json = json_path.display().to_string())
+                    }
+                    Err(err) => {
+                        let status = tr!("export-failed", error = err.to_string());
+                        eprintln!("{}", tr!("error", error = status.as_str()));
+                        status
+                    }
+                };
+
+                for mut text in status_query.iter_mut() {
+                    **text = status.clone();
+                }
+            }
+        }
+    }
+}
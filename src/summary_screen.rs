@@ -0,0 +1,72 @@
+use bevy::prelude::*;
+use cw_engine::{tr, Summary};
+
+use crate::screen::{despawn_screen, pressed, screen_root, spawn_button, Screen, PRIMARY};
+
+/// Rueckblick auf die zuletzt beendete Sitzung, gesetzt beim Verlassen des Trainings.
+#[derive(Resource)]
+pub struct LastSummary {
+    /// Warum die Sitzung endete, z.B. "Zeit abgelaufen"
+    pub reason: String,
+    pub summary: Summary,
+}
+
+#[derive(Component)]
+struct SummaryScreen;
+
+#[derive(Component)]
+struct ContinueButton;
+
+pub fn plugin(app: &mut App) {
+    app.add_systems(OnEnter(Screen::Summary), setup_summary)
+        .add_systems(OnExit(Screen::Summary), despawn_screen::<SummaryScreen>)
+        .add_systems(Update, summary_system.run_if(in_state(Screen::Summary)));
+}
+
+fn setup_summary(mut commands: Commands, last: Option<Res<LastSummary>>) {
+    commands.spawn(screen_root(SummaryScreen)).with_children(|panel| {
+        panel.spawn((
+            Node {
+                margin: UiRect::top(Val::Px(30.0)),
+                ..default()
+            },
+            Text::new(tr!("summary-title")),
+            TextFont {
+                font_size: 28.0,
+                ..default()
+            },
+            TextColor(Color::WHITE),
+        ));
+
+        if let Some(last) = &last {
+            panel.spawn((
+                Text::new(last.reason.clone()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.8, 0.8, 0.8)),
+            ));
+
+            panel.spawn((
+                Text::new(last.summary.to_string()),
+                TextFont {
+                    font_size: 18.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+            ));
+        }
+
+        spawn_button(panel, &tr!("button-continue"), Vec2::new(150.0, 40.0), PRIMARY, ContinueButton);
+    });
+}
+
+fn summary_system(
+    interaction_query: Query<(&Interaction, &ContinueButton), Changed<Interaction>>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if pressed(&interaction_query).next().is_some() {
+        next_screen.set(Screen::Home);
+    }
+}
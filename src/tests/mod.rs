@@ -0,0 +1,174 @@
+//! Die Anwendung ohne Fenster und Soundkarte: `MinimalPlugins`, die Wiedergabe in virtueller
+//! Zeit mit Mitschnitt und Tasten, die direkt in `ButtonInput` gedrueckt werden.
+
+mod training;
+
+use std::time::Duration;
+
+use bevy::audio::AudioSource;
+use bevy::input::keyboard::{Key, KeyboardInput, NativeKeyCode};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::prelude::*;
+use bevy::state::app::StatesPlugin;
+use bevy::time::TimeUpdateStrategy;
+use cw_engine::config::{AccessibilityConfig, KeysConfig};
+use cw_engine::loopback::Samples;
+use cw_engine::{test_util, PlayState, Profile, SessionEvent, Timings, TrainingSession};
+
+use crate::accessibility::Feedback;
+use crate::key_bindings::Hotkeys;
+use crate::playback::{CapturedAudio, PlaybackOutput, PlaybackSet};
+use crate::screen::Screen;
+use crate::{home, playback, screen, summary_screen, ActiveProfile, Trainer};
+
+/// Laenge eines Frames in virtueller Zeit.
+pub const FRAME: Duration = Duration::from_millis(10);
+
+/// Alle Zustaende der Sitzung, ohne direkte Wiederholungen.
+#[derive(Resource, Default)]
+pub struct StateLog(pub Vec<PlayState>);
+
+fn record_state(trainer: Res<Trainer>, mut log: ResMut<StateLog>) {
+    if log.0.last() != Some(&trainer.state()) {
+        log.0.push(trainer.state());
+    }
+}
+
+pub struct Harness {
+    pub app: App,
+}
+
+impl Harness {
+    /// Startseite mit einer Sitzung aus den Zeichen "a" und "b".
+    pub fn new() -> Self {
+        Self::with_sounds(&["a", "b"])
+    }
+
+    pub fn with_sounds(names: &[&str]) -> Self {
+        let mut session = TrainingSession::new(test_util::library(names), Timings::default());
+        session.handle(SessionEvent::Stop);
+        session.take_effects();
+
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, StatesPlugin, AssetPlugin::default()))
+            .init_asset::<AudioSource>()
+            .insert_resource(TimeUpdateStrategy::ManualDuration(FRAME))
+            .init_resource::<ButtonInput<KeyCode>>()
+            .add_event::<KeyboardInput>()
+            .init_resource::<InputFocus>()
+            .init_resource::<StateLog>()
+            .insert_resource(Trainer(session))
+            .insert_resource(ActiveProfile(Profile::temporary("test")))
+            .insert_resource(Feedback::new(&AccessibilityConfig::default()))
+            .insert_resource(Hotkeys::new(&KeysConfig::default()))
+            .insert_resource(PlaybackOutput::Virtual)
+            .init_resource::<CapturedAudio>()
+            .add_plugins((
+                screen::plugin,
+                home::plugin,
+                crate::training::plugin,
+                playback::plugin,
+                summary_screen::plugin,
+            ))
+            .add_systems(Update, record_state.before(PlaybackSet))
+            .add_systems(PostUpdate, record_state);
+
+        app.update();
+        let mut harness = Self { app };
+        harness.clear_log();
+        harness
+    }
+
+    /// Startet eine Sitzung wie der Benutzer mit der Start-Taste.
+    pub fn start(&mut self) {
+        self.press(KeyCode::F5);
+        // Der neue Screen gilt erst ab dem naechsten Frame
+        self.clear_log();
+        self.app.update();
+        assert_eq!(self.screen(), Screen::Training);
+    }
+
+    /// Drueckt eine Taste fuer genau einen Frame.
+    pub fn press(&mut self, key: KeyCode) {
+        self.app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(key);
+        self.app.update();
+
+        let mut keys = self.app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.release(key);
+        keys.clear();
+    }
+
+    /// Tippt `text`, wie es das Tastaturlayout liefert, ein Zeichen pro Frame.
+    pub fn type_text(&mut self, text: &str) {
+        for character in text.chars() {
+            let logical_key = if character == ' ' { Key::Space } else { Key::Character(character.to_string().into()) };
+            self.app.world_mut().send_event(KeyboardInput {
+                key_code: KeyCode::Unidentified(NativeKeyCode::Unidentified),
+                logical_key,
+                state: ButtonState::Pressed,
+                text: Some(character.to_string().into()),
+                repeat: false,
+                window: Entity::PLACEHOLDER,
+            });
+            self.app.update();
+        }
+    }
+
+    /// Tippt die Antwort und schickt sie mit Enter ab.
+    pub fn answer(&mut self, text: &str) {
+        self.type_text(text);
+        self.press(KeyCode::Enter);
+    }
+
+    /// Das gerade gesendete Zeichen und das jeweils andere.
+    pub fn expected(&self) -> String {
+        self.trainer().current().map(|sound| sound.name.clone()).expect("ein Zeichen wurde gesendet")
+    }
+
+    pub fn wrong(&self) -> String {
+        if self.expected() == "a" { "b".to_string() } else { "a".to_string() }
+    }
+
+    pub fn advance(&mut self, duration: Duration) {
+        for _ in 0..duration.div_duration_f32(FRAME).ceil() as u32 {
+            self.app.update();
+        }
+    }
+
+    /// Laesst Frames laufen, bis die Sitzung `state` erreicht; nach zehn Sekunden virtueller Zeit ist Schluss.
+    pub fn wait_for(&mut self, state: PlayState) {
+        for _ in 0..1000 {
+            if self.trainer().state() == state {
+                return;
+            }
+            self.app.update();
+        }
+        panic!("{:?} nicht erreicht, Ablauf: {:?}", state, self.states());
+    }
+
+    pub fn trainer(&self) -> &TrainingSession {
+        self.app.world().resource::<Trainer>()
+    }
+
+    pub fn profile(&self) -> &Profile {
+        self.app.world().resource::<ActiveProfile>()
+    }
+
+    pub fn screen(&self) -> Screen {
+        *self.app.world().resource::<State<Screen>>().get()
+    }
+
+    pub fn states(&self) -> Vec<PlayState> {
+        self.app.world().resource::<StateLog>().0.clone()
+    }
+
+    /// Mitschnitt der bisherigen Wiedergaben.
+    pub fn captured(&self) -> &[Samples] {
+        &self.app.world().resource::<CapturedAudio>().0
+    }
+
+    pub fn clear_log(&mut self) {
+        self.app.world_mut().resource_mut::<StateLog>().0.clear();
+    }
+}
@@ -0,0 +1,179 @@
+use std::fs;
+use std::time::Duration;
+
+use bevy::prelude::KeyCode;
+use cw_engine::loopback::Samples;
+use cw_engine::training_mode::DrillMode;
+use cw_engine::{PlayState, Prompt};
+
+use super::Harness;
+use crate::screen::Screen;
+use crate::Trainer;
+
+#[test]
+fn correct_answer_pauses_and_continues() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    let expected = harness.expected();
+    harness.answer(&expected);
+    harness.wait_for(PlayState::Playing);
+
+    assert_eq!(
+        harness.states(),
+        [
+            PlayState::ReadyToPlay,
+            PlayState::Playing,
+            PlayState::WaitingForAnswer,
+            PlayState::Pausing,
+            PlayState::ReadyToPlay,
+            PlayState::Playing,
+        ]
+    );
+    assert_eq!(harness.trainer().correct_count(), 1);
+    assert!(harness.profile().data.history[0].correct);
+}
+
+#[test]
+fn wrong_answer_repeats_three_times() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    let wrong = harness.wrong();
+    harness.answer(&wrong);
+    harness.wait_for(PlayState::Pausing);
+
+    assert_eq!(
+        harness.states(),
+        [
+            PlayState::ReadyToPlay,
+            PlayState::Playing,
+            PlayState::WaitingForAnswer,
+            PlayState::RepeatPausing,
+            PlayState::RepeatPlaying,
+            PlayState::RepeatPausing,
+            PlayState::RepeatPlaying,
+            PlayState::RepeatPausing,
+            PlayState::RepeatPlaying,
+            PlayState::RepeatPausing,
+            PlayState::Pausing,
+        ]
+    );
+    assert_eq!(harness.trainer().wrong_count(), 1);
+}
+
+#[test]
+fn answers_are_ignored_while_playing() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::Playing);
+
+    let expected = harness.expected();
+    harness.answer(&expected);
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    assert_eq!(harness.trainer().correct_count(), 0);
+    assert!(harness.profile().data.history.is_empty());
+}
+
+#[test]
+fn pause_key_freezes_the_session() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::Playing);
+
+    harness.press(KeyCode::F6);
+    let training_time = harness.trainer().training_time();
+    let captured = harness.captured()[0].data.len();
+    harness.advance(Duration::from_secs(5));
+    assert_eq!(harness.trainer().state(), PlayState::Playing);
+    assert_eq!(harness.trainer().training_time(), training_time);
+    assert_eq!(harness.captured()[0].data.len(), captured);
+
+    harness.press(KeyCode::F6);
+    harness.wait_for(PlayState::WaitingForAnswer);
+}
+
+#[test]
+fn replay_is_counted_with_the_answer() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    harness.press(KeyCode::F7);
+    assert_eq!(harness.trainer().state(), PlayState::Playing);
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    let expected = harness.expected();
+    harness.answer(&expected);
+    assert_eq!(harness.profile().data.history[0].replays, 1);
+    assert_eq!(harness.captured().len(), 2);
+}
+
+#[test]
+fn whole_sound_is_played_before_the_answer() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    let path = &harness.trainer().current().expect("ein Zeichen wurde gesendet").path;
+    let samples = Samples::decode(&fs::read(path).unwrap()).unwrap();
+    assert_eq!(harness.captured(), [samples]);
+}
+
+#[test]
+fn skip_moves_on_without_grading() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    harness.press(KeyCode::F8);
+    assert!(matches!(harness.trainer().prompt(), Prompt::Skipped { .. }));
+    harness.wait_for(PlayState::Playing);
+    assert!(harness.profile().data.history.is_empty());
+}
+
+#[test]
+fn start_stop_key_ends_the_session() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    harness.press(KeyCode::F5);
+    harness.advance(super::FRAME);
+    assert_eq!(harness.trainer().state(), PlayState::Stopped);
+    assert_eq!(harness.screen(), Screen::Summary);
+}
+
+#[test]
+fn ignored_skip_keeps_the_answer() {
+    let mut harness = Harness::new();
+    harness.start();
+    harness.wait_for(PlayState::WaitingForAnswer);
+
+    let expected = harness.expected();
+    harness.type_text(&expected);
+    harness.press(KeyCode::F6);
+    harness.press(KeyCode::F8);
+    harness.press(KeyCode::F6);
+    assert_eq!(harness.trainer().state(), PlayState::WaitingForAnswer);
+
+    harness.press(KeyCode::Enter);
+    assert!(harness.profile().data.history[0].correct);
+}
+
+#[test]
+fn punctuation_is_typed_while_copying() {
+    let mut harness = Harness::with_sounds(&["fragezeichen"]);
+    harness.app.world_mut().resource_mut::<Trainer>().mode_mut().drill = DrillMode::CopyBehind;
+    harness.start();
+    harness.wait_for(PlayState::Pausing);
+
+    harness.type_text("?");
+    harness.press(KeyCode::F5);
+    assert_eq!(harness.trainer().correct_count(), 1);
+    assert_eq!(harness.trainer().wrong_count(), 0);
+    assert!(harness.profile().data.history.iter().all(|answer| answer.correct));
+}
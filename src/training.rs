@@ -0,0 +1,772 @@
+use std::fs;
+
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::input::ButtonState;
+use bevy::input_focus::InputFocus;
+use bevy::ui::FocusPolicy;
+use bevy::{audio::AudioSource, prelude::*};
+use cw_engine::config::KeyAction;
+use cw_engine::{tr, Effect, PlayState, Prompt, SessionEvent, Sound};
+
+use crate::accessibility::{Feedback, LiveText};
+use crate::key_bindings::Hotkeys;
+use crate::playback::{spawn_playback, Playback, PlaybackEvent, PlaybackPaused, PlaybackSet};
+use crate::screen::{despawn_screen, pressed, ButtonColors, Screen, CONFIRM, DANGER, SECONDARY};
+use crate::summary_screen::LastSummary;
+use crate::{ActiveProfile, Trainer};
+
+#[derive(Component)]
+struct TrainingScreen;
+
+#[derive(Component)]
+struct CurrentFileText;
+
+#[derive(Component)]
+struct ScoreText;
+
+#[derive(Component)]
+struct AnswerInput;
+
+#[derive(Component)]
+struct AnswerText;
+
+#[derive(Component)]
+struct PlayPauseText;
+
+/// Deckt den Screen ab, solange die Sitzung angehalten ist.
+#[derive(Component)]
+struct PauseOverlay;
+
+#[derive(Component, Clone, Copy)]
+enum TrainingAction {
+    PlayPause,
+    Stop,
+    Submit,
+    Replay,
+}
+
+/// Was der Benutzer fuer das aktuelle Zeichen bisher eingetippt hat.
+#[derive(Resource, Default, Deref, DerefMut)]
+struct UserAnswer(String);
+
+/// Eine abgeschickte Antwort, egal ob per Button oder Enter; bewertet wird nur in `grade_answer_system`.
+#[derive(Event, Clone, Debug)]
+struct AnswerSubmitted(String);
+
+const ANSWER_BORDER: Color = Color::srgb(0.3, 0.3, 0.3);
+const ANSWER_BORDER_WAITING: Color = Color::srgb(0.9, 0.8, 0.2);
+
+pub fn plugin(app: &mut App) {
+    app.init_resource::<UserAnswer>()
+        .add_event::<AnswerSubmitted>()
+        .add_event::<PlaybackEvent>()
+        .add_systems(OnEnter(Screen::Training), (start_training, setup_training).chain())
+        .add_systems(OnExit(Screen::Training), despawn_screen::<TrainingScreen>)
+        .configure_sets(Update, PlaybackSet.run_if(in_state(Screen::Training)))
+        // Das Ende einer Sitzung erst auswerten, wenn alle Bewertungen ins Profil uebernommen sind
+        .add_systems(
+            Update,
+            (
+                (training_button_system, hotkey_system, keyboard_input_system, grade_answer_system)
+                    .chain()
+                    .before(PlaybackSet),
+                (audio_player_system, finish_training).chain().after(PlaybackSet),
+            )
+                .run_if(in_state(Screen::Training)),
+        )
+        .add_systems(
+            Update,
+            (
+                update_status_text,
+                update_score_display,
+                update_input_text,
+                update_play_pause_text,
+                update_pause_overlay,
+            )
+                .run_if(in_state(Screen::Training)),
+        )
+        .add_systems(Update, practice_time_system);
+}
+
+fn start_training(mut trainer: ResMut<Trainer>, mut profile: ResMut<ActiveProfile>, mut user_answer: ResMut<UserAnswer>) {
+    trainer.handle(SessionEvent::Start);
+    profile.begin_session();
+    user_answer.clear();
+}
+
+fn setup_training(mut commands: Commands, trainer: Res<Trainer>, hotkeys: Res<Hotkeys>) {
+    commands
+        .spawn((
+            Node {
+                width: Val::Percent(100.0),
+                height: Val::Percent(100.0),
+                flex_direction: FlexDirection::Column,
+                padding: UiRect::all(Val::Px(20.0)),
+                row_gap: Val::Px(12.0),
+                ..default()
+            },
+            TrainingScreen,
+        ))
+        .with_children(|screen| {
+            // Anzeige links, Score rechts oben
+            screen
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    justify_content: JustifyContent::SpaceBetween,
+                    column_gap: Val::Px(20.0),
+                    ..default()
+                })
+                .with_children(|header| {
+                    header.spawn((
+                        Text::new(trainer.prompt().to_string()),
+                        TextFont {
+                            font_size: 24.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(JustifyText::Left),
+                        Node {
+                            flex_grow: 1.0,
+                            flex_shrink: 1.0,
+                            min_height: Val::Px(60.0),
+                            ..default()
+                        },
+                        CurrentFileText,
+                        LiveText,
+                    ));
+
+                    header.spawn((
+                        Text::new(tr!("score", correct = trainer.correct_count(), wrong = trainer.wrong_count())),
+                        TextFont {
+                            font_size: 20.0,
+                            ..default()
+                        },
+                        TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                        TextLayout::new_with_justify(JustifyText::Right),
+                        ScoreText,
+                    ));
+                });
+
+            screen
+                .spawn(Node {
+                    flex_grow: 1.0,
+                    flex_direction: FlexDirection::Column,
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    row_gap: Val::Px(12.0),
+                    ..default()
+                })
+                .with_children(|middle| {
+                    // Das Eingabefeld bleibt stehen und ist nur hervorgehoben, solange eine Antwort erwartet wird
+                    middle
+                        .spawn((
+                            Node {
+                                width: Val::Percent(60.0),
+                                min_width: Val::Px(200.0),
+                                max_width: Val::Px(500.0),
+                                height: Val::Px(50.0),
+                                justify_content: JustifyContent::Center,
+                                align_items: AlignItems::Center,
+                                border: UiRect::all(Val::Px(2.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::srgb(0.15, 0.15, 0.15)),
+                            BorderColor(ANSWER_BORDER),
+                            AnswerInput,
+                        ))
+                        .with_children(|input| {
+                            input.spawn((
+                                Text::new(""),
+                                TextFont {
+                                    font_size: 24.0,
+                                    ..default()
+                                },
+                                TextColor(Color::WHITE),
+                                AnswerText,
+                            ));
+                        });
+
+                    middle
+                        .spawn(Node {
+                            column_gap: Val::Px(20.0),
+                            ..default()
+                        })
+                        .with_children(|row| {
+                            spawn_button(row, &tr!("button-replay"), SECONDARY, TrainingAction::Replay, ());
+                            spawn_button(row, &tr!("button-submit"), CONFIRM, TrainingAction::Submit, ());
+                        });
+                });
+
+            screen
+                .spawn(Node {
+                    width: Val::Percent(100.0),
+                    flex_wrap: FlexWrap::Wrap,
+                    justify_content: JustifyContent::Center,
+                    column_gap: Val::Px(20.0),
+                    row_gap: Val::Px(10.0),
+                    ..default()
+                })
+                .with_children(|controls| {
+                    spawn_button(controls, &tr!("button-pause"), CONFIRM, TrainingAction::PlayPause, PlayPauseText);
+                    spawn_button(controls, &tr!("button-stop"), DANGER, TrainingAction::Stop, ());
+                });
+
+            screen.spawn((
+                Text::new(tr!(
+                    "hotkeys-hint",
+                    start_stop = hotkeys.name(KeyAction::StartStop),
+                    pause = hotkeys.name(KeyAction::Pause),
+                    replay = hotkeys.name(KeyAction::Replay),
+                    skip = hotkeys.name(KeyAction::Skip),
+                )),
+                TextFont {
+                    font_size: 14.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                TextLayout::new_with_justify(JustifyText::Center),
+                Node {
+                    align_self: AlignSelf::Center,
+                    ..default()
+                },
+            ));
+        });
+}
+
+/// Wie `screen::spawn_button`, aber mit einer Komponente an der Beschriftung.
+fn spawn_button(
+    parent: &mut ChildSpawnerCommands,
+    text: &str,
+    colors: ButtonColors,
+    action: TrainingAction,
+    label: impl Bundle,
+) {
+    parent
+        .spawn((
+            Button,
+            Node {
+                width: Val::Px(150.0),
+                height: Val::Px(50.0),
+                justify_content: JustifyContent::Center,
+                align_items: AlignItems::Center,
+                ..default()
+            },
+            BackgroundColor(colors.normal),
+            colors,
+            action,
+        ))
+        .with_children(|button| {
+            button.spawn((
+                Text::new(text),
+                TextFont {
+                    font_size: 24.0,
+                    ..default()
+                },
+                TextColor(Color::WHITE),
+                label,
+            ));
+        });
+}
+
+fn training_button_system(
+    interaction_query: Query<(&Interaction, &TrainingAction), Changed<Interaction>>,
+    mut trainer: ResMut<Trainer>,
+    user_answer: Res<UserAnswer>,
+    mut submitted: EventWriter<AnswerSubmitted>,
+) {
+    for action in pressed(&interaction_query) {
+        match action {
+            TrainingAction::Submit => {
+                if !user_answer.is_empty() {
+                    submitted.write(AnswerSubmitted(user_answer.clone()));
+                }
+            }
+            TrainingAction::PlayPause => {
+                let event = if trainer.is_paused() { SessionEvent::Resume } else { SessionEvent::Pause };
+                trainer.handle(event);
+            }
+            TrainingAction::Stop => trainer.handle(SessionEvent::Stop),
+            TrainingAction::Replay => trainer.handle(SessionEvent::Replay),
+        }
+    }
+}
+
+/// Die belegten Tasten wirken in jedem Zustand, auch waehrend ein Zeichen laeuft.
+fn hotkey_system(
+    keys: Res<ButtonInput<KeyCode>>,
+    hotkeys: Res<Hotkeys>,
+    mut trainer: ResMut<Trainer>,
+    mut user_answer: ResMut<UserAnswer>,
+) {
+    if hotkeys.just_pressed(&keys, KeyAction::StartStop) {
+        trainer.handle(SessionEvent::Stop);
+    }
+
+    if hotkeys.just_pressed(&keys, KeyAction::Pause) {
+        let event = if trainer.is_paused() { SessionEvent::Resume } else { SessionEvent::Pause };
+        trainer.handle(event);
+    }
+
+    if hotkeys.just_pressed(&keys, KeyAction::Replay) {
+        trainer.handle(SessionEvent::Replay);
+    }
+
+    if hotkeys.just_pressed(&keys, KeyAction::Skip) {
+        let state = trainer.state();
+        trainer.handle(SessionEvent::Skip);
+        // Wird die Taste ignoriert, z.B. in der Pause, bleibt die angefangene Antwort stehen
+        if trainer.state() != state {
+            user_answer.clear();
+        }
+    }
+}
+
+/// Getippt wird, was das Tastaturlayout liefert, also auch Satzzeichen wie "?" oder "/".
+fn keyboard_input_system(
+    mut typed: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    focus: Res<InputFocus>,
+    button_query: Query<(), With<Button>>,
+    mut trainer: ResMut<Trainer>,
+    mut user_answer: ResMut<UserAnswer>,
+    mut submitted: EventWriter<AnswerSubmitted>,
+) {
+    // Immer abholen, sonst kaemen Eingaben waehrend der Wiedergabe spaeter noch an
+    let mut characters = Vec::new();
+    for event in typed.read().filter(|event| event.state == ButtonState::Pressed) {
+        match &event.logical_key {
+            Key::Character(text) => characters.extend(text.chars().filter(|character| !character.is_control())),
+            Key::Space => characters.push(' '),
+            _ => {}
+        }
+    }
+
+    let streaming = trainer.is_streaming();
+    if trainer.is_paused() || (trainer.state() != PlayState::WaitingForAnswer && !streaming) {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::Backspace) {
+        if streaming {
+            trainer.handle(SessionEvent::Erase);
+        } else {
+            user_answer.pop();
+        }
+    }
+
+    if streaming && keys.just_pressed(KeyCode::Enter) {
+        return;
+    }
+
+    // Enter und Leertaste gehoeren dann dem Button
+    let button_focused = focus.0.is_some_and(|entity| button_query.contains(entity));
+    if button_focused && (keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space)) {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::Enter) && !user_answer.is_empty() {
+        submitted.write(AnswerSubmitted(user_answer.clone()));
+        return;
+    }
+
+    for character in characters {
+        if streaming {
+            trainer.handle(SessionEvent::Typed(character));
+        } else {
+            user_answer.push(character);
+        }
+    }
+}
+
+/// Gibt abgeschickte Antworten an die Engine weiter, die sie bewertet und den Ablauf fortsetzt.
+fn grade_answer_system(
+    mut submitted: EventReader<AnswerSubmitted>,
+    mut trainer: ResMut<Trainer>,
+    mut user_answer: ResMut<UserAnswer>,
+) {
+    for AnswerSubmitted(answer) in submitted.read() {
+        // Button und Enter im selben Frame ergeben nur eine Antwort
+        if trainer.is_paused() || trainer.state() != PlayState::WaitingForAnswer {
+            continue;
+        }
+
+        trainer.handle(SessionEvent::AnswerSubmitted(answer.clone()));
+        user_answer.clear();
+    }
+}
+
+/// Zeigt die Eingabe und hebt das Feld hervor, solange eine Antwort erwartet wird.
+fn update_input_text(
+    trainer: Res<Trainer>,
+    user_answer: Res<UserAnswer>,
+    mut text_query: Query<&mut Text, With<AnswerText>>,
+    mut border_query: Query<&mut BorderColor, With<AnswerInput>>,
+) {
+    if user_answer.is_changed() {
+        for mut text in text_query.iter_mut() {
+            **text = user_answer.to_string();
+        }
+    }
+
+    let border = if trainer.state() == PlayState::WaitingForAnswer { ANSWER_BORDER_WAITING } else { ANSWER_BORDER };
+    for mut color in border_query.iter_mut() {
+        if color.0 != border {
+            color.0 = border;
+        }
+    }
+}
+
+fn update_status_text(
+    trainer: Res<Trainer>,
+    mut shown: Local<Option<Prompt>>,
+    mut text_query: Query<(&mut Text, Ref<CurrentFileText>)>,
+) {
+    // Beim erneuten Betreten gibt es ein neues Textfeld, das auf jeden Fall gefuellt werden muss
+    let added = text_query.iter().any(|(_, marker)| marker.is_added());
+    if !added && shown.as_ref() == Some(trainer.prompt()) {
+        return;
+    }
+
+    for (mut text, _) in text_query.iter_mut() {
+        **text = trainer.prompt().to_string();
+    }
+    *shown = Some(trainer.prompt().clone());
+}
+
+fn update_score_display(trainer: Res<Trainer>, mut score_query: Query<&mut Text, With<ScoreText>>) {
+    let score = tr!("score", correct = trainer.correct_count(), wrong = trainer.wrong_count());
+
+    for mut text in score_query.iter_mut() {
+        if **text != score {
+            **text = score.clone();
+        }
+    }
+}
+
+fn update_play_pause_text(trainer: Res<Trainer>, mut text_query: Query<&mut Text, With<PlayPauseText>>) {
+    let label = tr!(if trainer.is_paused() { "button-resume" } else { "button-pause" });
+
+    for mut text in text_query.iter_mut() {
+        if **text != label {
+            **text = label.clone();
+        }
+    }
+}
+
+/// Legt waehrend der Pause eine Abdeckung mit eigenem Weiter-Button ueber den Screen,
+/// damit nichts darunter angeklickt werden kann.
+fn update_pause_overlay(
+    mut commands: Commands,
+    trainer: Res<Trainer>,
+    hotkeys: Res<Hotkeys>,
+    screen_query: Query<Entity, With<TrainingScreen>>,
+    overlay_query: Query<Entity, With<PauseOverlay>>,
+) {
+    let paused = trainer.is_paused();
+
+    if !paused {
+        for entity in overlay_query.iter() {
+            commands.entity(entity).despawn();
+        }
+        return;
+    }
+
+    if !overlay_query.is_empty() {
+        return;
+    }
+
+    for screen in screen_query.iter() {
+        commands.entity(screen).with_children(|screen| {
+            screen
+                .spawn((
+                    Node {
+                        position_type: PositionType::Absolute,
+                        width: Val::Percent(100.0),
+                        height: Val::Percent(100.0),
+                        flex_direction: FlexDirection::Column,
+                        justify_content: JustifyContent::Center,
+                        align_items: AlignItems::Center,
+                        row_gap: Val::Px(20.0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+                    FocusPolicy::Block,
+                    GlobalZIndex(10),
+                    PauseOverlay,
+                ))
+                .with_children(|overlay| {
+                    overlay.spawn((
+                        Text::new(tr!("pause-overlay", key = hotkeys.name(KeyAction::Pause))),
+                        TextFont {
+                            font_size: 28.0,
+                            ..default()
+                        },
+                        TextColor(Color::WHITE),
+                        TextLayout::new_with_justify(JustifyText::Center),
+                        LiveText,
+                    ));
+
+                    spawn_button(overlay, &tr!("button-resume"), CONFIRM, TrainingAction::PlayPause, ());
+                });
+        });
+    }
+}
+
+/// Haelt die beendete Sitzung im Profil fest und wechselt zur Zusammenfassung.
+fn finish_training(
+    mut commands: Commands,
+    trainer: Res<Trainer>,
+    mut profile: ResMut<ActiveProfile>,
+    mut next_screen: ResMut<NextState<Screen>>,
+) {
+    if trainer.state() != PlayState::Stopped {
+        return;
+    }
+
+    let summary = profile.finish_session(trainer.training_time().as_secs_f32());
+    profile.save();
+    commands.insert_resource(LastSummary {
+        reason: trainer.prompt().to_string(),
+        summary,
+    });
+    next_screen.set(Screen::Summary);
+}
+
+/// Zaehlt die Zeit, in der tatsaechlich trainiert wird, fuer die Statistik.
+fn practice_time_system(
+    trainer: Res<Trainer>,
+    mut profile: ResMut<ActiveProfile>,
+    mut pending: Local<f32>,
+    time: Res<Time>,
+) {
+    let training = trainer.state() != PlayState::Stopped && !trainer.is_paused();
+
+    if training {
+        *pending += time.delta_secs();
+    }
+
+    // Nur gelegentlich ins Profil uebernehmen, damit es nicht jeden Frame geaendert wird
+    if *pending >= 10.0 || (!training && *pending > 0.0) {
+        profile.add_practice_time(*pending);
+        *pending = 0.0;
+
+        if !training {
+            profile.save();
+        }
+    }
+}
+
+/// Treibt die Engine mit der verstrichenen Zeit an und setzt ihre Effekte in Bevy um.
+#[allow(clippy::too_many_arguments)]
+fn audio_player_system(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    playback_query: Query<(Entity, &Playback, Option<&AudioSink>)>,
+    mut playback_events: EventReader<PlaybackEvent>,
+    mut trainer: ResMut<Trainer>,
+    mut profile: ResMut<ActiveProfile>,
+    mut user_answer: ResMut<UserAnswer>,
+    mut audio_sources: ResMut<Assets<AudioSource>>,
+    mut feedback: ResMut<Feedback>,
+    time: Res<Time>,
+) {
+    trainer.tick(time.delta());
+
+    for event in playback_events.read() {
+        match event {
+            PlaybackEvent::Started => trainer.handle(SessionEvent::PlaybackStarted),
+            PlaybackEvent::Finished => {
+                trainer.handle(SessionEvent::PlaybackFinished);
+
+                if trainer.state() == PlayState::WaitingForAnswer {
+                    user_answer.clear();
+                }
+            }
+            PlaybackEvent::Failed(error) => trainer.handle(SessionEvent::PlaybackFailed(error.clone())),
+        }
+    }
+
+    for effect in trainer.take_effects() {
+        match effect {
+            Effect::Play(sound) => {
+                feedback.interrupt();
+                for (entity, _, _) in playback_query.iter() {
+                    commands.entity(entity).try_despawn();
+                }
+
+                match audio_handle(&sound, &asset_server, &mut audio_sources) {
+                    Ok(handle) => spawn_playback(&mut commands, handle),
+                    Err(error) => {
+                        eprintln!("{}", tr!("error", error = error.as_str()));
+                        trainer.handle(SessionEvent::PlaybackFailed(error));
+                    }
+                }
+            }
+            Effect::StopAudio => {
+                for (entity, _, _) in playback_query.iter() {
+                    commands.entity(entity).try_despawn();
+                }
+            }
+            Effect::PauseAudio => {
+                for (entity, _, sink) in playback_query.iter() {
+                    commands.entity(entity).insert(PlaybackPaused);
+                    if let Some(sink) = sink {
+                        sink.pause();
+                    }
+                }
+            }
+            Effect::ResumeAudio => {
+                for (entity, playback, sink) in playback_query.iter() {
+                    commands.entity(entity).remove::<PlaybackPaused>();
+                    if let Some(sink) = sink {
+                        sink.play();
+                    }
+
+                    // Ein Ende waehrend der Pause hat die Engine nicht angenommen
+                    if *playback == Playback::Finished {
+                        trainer.handle(SessionEvent::PlaybackFinished);
+                    }
+                }
+            }
+            Effect::Graded(grade) => {
+                feedback.graded(&grade);
+                profile.record(&grade);
+                profile.save();
+                trainer.mode_mut().learn(&profile.data.confusion);
+            }
+        }
+    }
+}
+
+/// Sounds unter `assets` laufen ueber den AssetServer, alles andere (z.B. erzeugte
+/// Zeichen im Cache-Verzeichnis) wird direkt eingelesen.
+pub(crate) fn audio_handle(
+    sound: &Sound,
+    asset_server: &AssetServer,
+    audio_sources: &mut Assets<AudioSource>,
+) -> Result<Handle<AudioSource>, String> {
+    if let Ok(asset_path) = sound.path.strip_prefix("assets") {
+        return Ok(asset_server.load(asset_path.to_owned()));
+    }
+
+    fs::read(&sound.path)
+        .map(|bytes| audio_sources.add(AudioSource { bytes: bytes.into() }))
+        .map_err(|err| tr!("error-unreadable", path = sound.path.display().to_string(), error = err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use bevy::input_focus::InputFocus;
+    use bevy::prelude::*;
+    use cw_engine::{test_util, Effect, PlayState, Timings, TrainingSession};
+
+    use super::*;
+
+    /// App mit nur den Systemen fuer die Eingabe, in der die Sitzung schon auf eine Antwort wartet.
+    fn waiting_app(answer: &str) -> App {
+        let mut session = TrainingSession::new(test_util::library(&["a"]), Timings::default());
+        session.tick(Duration::from_millis(1));
+        session.handle(SessionEvent::PlaybackStarted);
+        session.handle(SessionEvent::PlaybackFinished);
+        session.take_effects();
+        assert_eq!(session.state(), PlayState::WaitingForAnswer);
+
+        let mut app = App::new();
+        app.add_plugins(MinimalPlugins)
+            .init_resource::<ButtonInput<KeyCode>>()
+            .init_resource::<InputFocus>()
+            .insert_resource(Trainer(session))
+            .insert_resource(UserAnswer(answer.to_string()))
+            .add_event::<KeyboardInput>()
+            .add_event::<AnswerSubmitted>()
+            .add_event::<PlaybackEvent>()
+            .add_systems(Update, (training_button_system, keyboard_input_system, grade_answer_system).chain());
+        app
+    }
+
+    fn click_submit(app: &mut App) {
+        app.world_mut().spawn((Button, Interaction::Pressed, TrainingAction::Submit));
+        app.update();
+    }
+
+    fn press_enter(app: &mut App) {
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        app.update();
+    }
+
+    fn graded(app: &mut App) -> Vec<Effect> {
+        app.world_mut()
+            .resource_mut::<Trainer>()
+            .take_effects()
+            .into_iter()
+            .filter(|effect| matches!(effect, Effect::Graded(_)))
+            .collect()
+    }
+
+    #[test]
+    fn submit_button_grades_answer() {
+        let mut app = waiting_app("a");
+        click_submit(&mut app);
+
+        let trainer = app.world().resource::<Trainer>();
+        assert_eq!(trainer.state(), PlayState::Pausing);
+        assert_eq!(trainer.correct_count(), 1);
+        assert!(app.world().resource::<UserAnswer>().is_empty());
+    }
+
+    #[test]
+    fn enter_grades_answer() {
+        let mut app = waiting_app("x");
+        press_enter(&mut app);
+
+        let trainer = app.world().resource::<Trainer>();
+        assert_eq!(trainer.state(), PlayState::RepeatPausing);
+        assert_eq!(trainer.wrong_count(), 1);
+        assert!(app.world().resource::<UserAnswer>().is_empty());
+    }
+
+    #[test]
+    fn button_and_enter_grade_alike() {
+        let mut mouse = waiting_app("a");
+        click_submit(&mut mouse);
+        let mut keyboard = waiting_app("a");
+        press_enter(&mut keyboard);
+
+        let effects = graded(&mut mouse);
+        assert_eq!(effects.len(), 1);
+        assert_eq!(effects, graded(&mut keyboard));
+    }
+
+    #[test]
+    fn button_and_enter_together_grade_once() {
+        let mut app = waiting_app("a");
+        app.world_mut().resource_mut::<ButtonInput<KeyCode>>().press(KeyCode::Enter);
+        click_submit(&mut app);
+
+        assert_eq!(graded(&mut app).len(), 1);
+    }
+
+    #[test]
+    fn answers_wait_while_paused() {
+        let mut app = waiting_app("a");
+        app.world_mut().resource_mut::<Trainer>().handle(SessionEvent::Pause);
+        click_submit(&mut app);
+        press_enter(&mut app);
+
+        assert_eq!(app.world().resource::<Trainer>().state(), PlayState::WaitingForAnswer);
+        assert_eq!(app.world().resource::<UserAnswer>().as_str(), "a");
+        assert!(graded(&mut app).is_empty());
+    }
+
+    #[test]
+    fn empty_answer_is_ignored() {
+        let mut app = waiting_app("");
+        click_submit(&mut app);
+        press_enter(&mut app);
+
+        assert_eq!(app.world().resource::<Trainer>().state(), PlayState::WaitingForAnswer);
+        assert!(graded(&mut app).is_empty());
+    }
+}
@@ -0,0 +1,341 @@
+use std::time::Duration;
+
+use cw_engine::config::{BindableKey, KeyAction, KeysConfig};
+use cw_engine::{training_mode::DrillMode, AudioBackend, AudioEvent, Effect, PlayState, Profile, SessionEvent, Summary, TrainingSession};
+use ratatui::crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Die Taste im Terminal; fehlt hier eine neue `BindableKey`, baut das Frontend nicht mehr.
+fn key_code(key: BindableKey) -> KeyCode {
+    match key {
+        BindableKey::F1 => KeyCode::F(1),
+        BindableKey::F2 => KeyCode::F(2),
+        BindableKey::F3 => KeyCode::F(3),
+        BindableKey::F4 => KeyCode::F(4),
+        BindableKey::F5 => KeyCode::F(5),
+        BindableKey::F6 => KeyCode::F(6),
+        BindableKey::F7 => KeyCode::F(7),
+        BindableKey::F8 => KeyCode::F(8),
+        BindableKey::F9 => KeyCode::F(9),
+        BindableKey::F10 => KeyCode::F(10),
+        BindableKey::F12 => KeyCode::F(12),
+        BindableKey::Insert => KeyCode::Insert,
+        BindableKey::Delete => KeyCode::Delete,
+        BindableKey::Home => KeyCode::Home,
+        BindableKey::End => KeyCode::End,
+        BindableKey::PageUp => KeyCode::PageUp,
+        BindableKey::PageDown => KeyCode::PageDown,
+        BindableKey::Up => KeyCode::Up,
+        BindableKey::Down => KeyCode::Down,
+        BindableKey::Left => KeyCode::Left,
+        BindableKey::Right => KeyCode::Right,
+    }
+}
+
+/// Alles, was das Terminal-Frontend zwischen zwei Frames behalten muss.
+pub struct App {
+    pub trainer: TrainingSession,
+    pub profile: Profile,
+    pub audio: Box<dyn AudioBackend>,
+    /// Dieselbe Belegung wie im Fenster
+    pub keys: KeysConfig,
+    /// Was der Benutzer fuer das aktuelle Zeichen bisher eingetippt hat
+    pub answer: String,
+    pub quit: bool,
+    /// Rueckblick auf die zuletzt beendete Sitzung, bis die naechste beginnt
+    pub summary: Option<Summary>,
+    running: bool,
+    pending_practice: f32,
+}
+
+impl App {
+    pub fn new(mut trainer: TrainingSession, profile: Profile, audio: Box<dyn AudioBackend>, keys: KeysConfig) -> Self {
+        trainer.mode_mut().learn(&profile.data.confusion);
+        // Erst mit der Start-Taste beginnt eine Sitzung
+        trainer.handle(SessionEvent::Stop);
+        trainer.take_effects();
+
+        Self {
+            trainer,
+            profile,
+            audio,
+            keys,
+            answer: String::new(),
+            quit: false,
+            summary: None,
+            running: false,
+            pending_practice: 0.0,
+        }
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) {
+        if let Some(action) = KeyAction::ALL.into_iter().find(|action| self.keys.key(*action).map(key_code) == Some(key.code)) {
+            self.handle_action(action);
+            return;
+        }
+
+        match key.code {
+            KeyCode::Esc => self.quit = true,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => self.quit = true,
+            // Tab ist nicht belegbar und tippt kein Zeichen
+            KeyCode::Tab if self.trainer.state() == PlayState::Stopped || self.trainer.state() == PlayState::ReadyToPlay => {
+                let mode = self.trainer.mode_mut();
+                mode.drill = if mode.drill == DrillMode::CopyBehind { DrillMode::Random } else { DrillMode::CopyBehind };
+            }
+            // Wie in der GUI: waehrend der Pause bleibt die Antwort unveraendert stehen
+            _ if self.trainer.is_paused() => {}
+            KeyCode::Backspace if self.trainer.is_streaming() => self.trainer.handle(SessionEvent::Erase),
+            KeyCode::Char(character) if self.trainer.is_streaming() && !character.is_control() => {
+                self.trainer.handle(SessionEvent::Typed(character));
+            }
+            _ if self.trainer.state() != PlayState::WaitingForAnswer => {}
+            KeyCode::Backspace => {
+                self.answer.pop();
+            }
+            KeyCode::Enter if !self.answer.is_empty() => {
+                self.trainer.handle(SessionEvent::AnswerSubmitted(self.answer.clone()));
+                self.answer.clear();
+            }
+            KeyCode::Char(character) if !character.is_control() => self.answer.push(character),
+            _ => {}
+        }
+    }
+
+    fn handle_action(&mut self, action: KeyAction) {
+        match action {
+            KeyAction::StartStop => {
+                let event = if self.trainer.state() == PlayState::Stopped { SessionEvent::Start } else { SessionEvent::Stop };
+                self.trainer.handle(event);
+            }
+            KeyAction::Pause => {
+                let event = if self.trainer.is_paused() { SessionEvent::Resume } else { SessionEvent::Pause };
+                self.trainer.handle(event);
+            }
+            KeyAction::Replay => self.trainer.handle(SessionEvent::Replay),
+            KeyAction::Skip => {
+                let state = self.trainer.state();
+                self.trainer.handle(SessionEvent::Skip);
+                if self.trainer.state() != state {
+                    self.answer.clear();
+                }
+            }
+        }
+    }
+
+    /// Treibt die Engine mit der verstrichenen Zeit an und setzt ihre Effekte um.
+    pub fn update(&mut self, delta: Duration) {
+        self.trainer.tick(delta);
+        self.audio.advance(delta);
+
+        for event in self.audio.take_events() {
+            self.trainer.handle(event.into());
+
+            if event == AudioEvent::Finished && self.trainer.state() == PlayState::WaitingForAnswer {
+                self.answer.clear();
+            }
+        }
+
+        for effect in self.trainer.take_effects() {
+            self.audio.apply(&effect);
+
+            match effect {
+                Effect::Play(_) => {
+                    if let Some(error) = self.audio.last_error() {
+                        self.trainer.handle(SessionEvent::PlaybackFailed(error.to_string()));
+                    }
+                }
+                Effect::Graded(grade) => {
+                    self.profile.record(&grade);
+                    self.profile.save();
+                    self.trainer.mode_mut().learn(&self.profile.data.confusion);
+                }
+                _ => {}
+            }
+        }
+
+        self.track_session();
+        self.track_practice_time(delta);
+    }
+
+    fn track_session(&mut self) {
+        let stopped = self.trainer.state() == PlayState::Stopped;
+
+        if !stopped && !self.running {
+            self.running = true;
+            self.summary = None;
+            self.profile.begin_session();
+        } else if stopped && self.running {
+            self.running = false;
+            self.summary = Some(self.profile.finish_session(self.trainer.training_time().as_secs_f32()));
+            self.profile.save();
+        }
+    }
+
+    fn track_practice_time(&mut self, delta: Duration) {
+        let training = self.trainer.state() != PlayState::Stopped && !self.trainer.is_paused();
+
+        if training {
+            self.pending_practice += delta.as_secs_f32();
+        }
+
+        // Nur gelegentlich ins Profil uebernehmen, damit es nicht jeden Frame geschrieben wird
+        if self.pending_practice >= 10.0 || (!training && self.pending_practice > 0.0) {
+            self.profile.add_practice_time(self.pending_practice);
+            self.pending_practice = 0.0;
+
+            if !training {
+                self.profile.save();
+            }
+        }
+    }
+
+    /// Uebernimmt die noch nicht gezaehlte Uebungszeit beim Beenden.
+    pub fn finish(&mut self) {
+        self.audio.stop();
+
+        if self.running {
+            self.profile.finish_session(self.trainer.training_time().as_secs_f32());
+        }
+
+        if self.pending_practice > 0.0 {
+            self.profile.add_practice_time(self.pending_practice);
+            self.pending_practice = 0.0;
+        }
+        self.profile.save();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use cw_engine::{test_util, Prompt, SilentBackend, Sound, Timings};
+
+    use super::*;
+
+    /// Jede Wiedergabe scheitert sofort, wie bei einer kaputten Datei.
+    struct FailingBackend;
+
+    impl AudioBackend for FailingBackend {
+        fn play(&mut self, _sound: &Sound) {}
+        fn stop(&mut self) {}
+        fn pause(&mut self) {}
+        fn resume(&mut self) {}
+
+        fn is_playing(&self) -> bool {
+            false
+        }
+
+        fn take_events(&mut self) -> Vec<AudioEvent> {
+            Vec::new()
+        }
+
+        fn last_error(&self) -> Option<&str> {
+            Some("kaputt")
+        }
+    }
+
+    fn app(keys: KeysConfig) -> App {
+        app_with(keys, Box::new(SilentBackend::default()))
+    }
+
+    fn app_with(keys: KeysConfig, audio: Box<dyn AudioBackend>) -> App {
+        let session = TrainingSession::new(test_util::library(&["a", "b"]), Timings::default());
+        App::new(session, Profile::temporary("test"), audio, keys)
+    }
+
+    fn press(app: &mut App, code: KeyCode) {
+        app.handle_key(KeyEvent::new(code, KeyModifiers::NONE));
+        app.update(Duration::from_millis(10));
+    }
+
+    fn wait_for_answer(app: &mut App) {
+        press(app, KeyCode::F(5));
+        for _ in 0..1000 {
+            if app.trainer.state() == PlayState::WaitingForAnswer {
+                return;
+            }
+            app.update(Duration::from_millis(10));
+        }
+        panic!("keine Antwort erwartet: {:?}", app.trainer.state());
+    }
+
+    #[test]
+    fn keys_follow_the_config() {
+        let mut keys = KeysConfig::default();
+        keys.assign(KeyAction::StartStop, BindableKey::Home);
+        let mut app = app(keys);
+
+        press(&mut app, KeyCode::F(5));
+        assert_eq!(app.trainer.state(), PlayState::Stopped);
+
+        press(&mut app, KeyCode::Home);
+        assert_ne!(app.trainer.state(), PlayState::Stopped);
+        press(&mut app, KeyCode::Home);
+        assert_eq!(app.trainer.state(), PlayState::Stopped);
+    }
+
+    #[test]
+    fn skip_clears_the_answer() {
+        let mut app = app(KeysConfig::default());
+        wait_for_answer(&mut app);
+
+        press(&mut app, KeyCode::Char('a'));
+        press(&mut app, KeyCode::F(8));
+        assert!(app.answer.is_empty());
+        assert_eq!(app.trainer.state(), PlayState::Pausing);
+        assert!(app.profile.data.history.is_empty());
+    }
+
+    #[test]
+    fn ignored_skip_keeps_the_answer() {
+        let mut app = app(KeysConfig::default());
+        wait_for_answer(&mut app);
+
+        press(&mut app, KeyCode::Char('a'));
+        press(&mut app, KeyCode::F(6));
+        press(&mut app, KeyCode::F(8));
+        press(&mut app, KeyCode::F(6));
+        assert_eq!(app.answer, "a");
+        assert_eq!(app.trainer.state(), PlayState::WaitingForAnswer);
+    }
+
+    #[test]
+    fn tab_toggles_copy_behind_only_between_sessions() {
+        let mut app = app(KeysConfig::default());
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.trainer.mode().drill, DrillMode::CopyBehind);
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.trainer.mode().drill, DrillMode::Random);
+
+        wait_for_answer(&mut app);
+        press(&mut app, KeyCode::Tab);
+        assert_eq!(app.trainer.mode().drill, DrillMode::Random);
+    }
+
+    #[test]
+    fn failed_playback_is_reported_to_the_session() {
+        let mut app = app_with(KeysConfig::default(), Box::new(FailingBackend));
+        press(&mut app, KeyCode::F(5));
+        app.update(Duration::from_millis(10));
+
+        assert!(matches!(app.trainer.prompt(), Prompt::PlaybackFailed { error, .. } if error == "kaputt"));
+        assert_ne!(app.trainer.state(), PlayState::WaitingForAnswer);
+    }
+
+    #[test]
+    fn answer_waits_while_paused() {
+        let mut app = app(KeysConfig::default());
+        wait_for_answer(&mut app);
+
+        press(&mut app, KeyCode::Char('a'));
+        press(&mut app, KeyCode::F(6));
+        press(&mut app, KeyCode::Char('b'));
+        press(&mut app, KeyCode::Backspace);
+        press(&mut app, KeyCode::Enter);
+        assert_eq!(app.answer, "a");
+        assert_eq!(app.trainer.state(), PlayState::WaitingForAnswer);
+
+        press(&mut app, KeyCode::F(6));
+        press(&mut app, KeyCode::Enter);
+        assert!(app.answer.is_empty());
+        assert_eq!(app.profile.data.history.len(), 1);
+    }
+}
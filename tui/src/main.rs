@@ -0,0 +1,118 @@
+//! Terminal-Frontend des CW Listen Trainers auf Basis derselben Engine wie die GUI.
+//!
+//! Aufruf: `cw-listen-tui [--audio rodio|loopback|silent]`
+//!
+//! `loopback` braucht keine Soundkarte und spielt jedes Zeichen stumm in seiner tatsaechlichen Laenge.
+
+use std::{
+    env,
+    io,
+    process::exit,
+    time::{Duration, Instant},
+};
+
+use cw_engine::{
+    i18n, profile::DEFAULT_PROFILE, tr, AudioBackend, Config, LibraryError, LoopbackBackend, Profile, RodioBackend, SilentBackend,
+    SoundLibrary, TrainingSession,
+};
+use ratatui::{
+    DefaultTerminal,
+    crossterm::event::{self, Event, KeyEventKind},
+};
+
+mod app;
+mod ui;
+
+use app::App;
+
+/// Wie oft ohne Tastendruck neu gezeichnet und die Engine weitergeschaltet wird.
+const FRAME: Duration = Duration::from_millis(30);
+
+fn main() {
+    // Fehler in der Einstellungsdatei erscheinen in der Sprache des Systems
+    let config = Config::load(&Config::default_path()).unwrap_or_else(|err| {
+        eprintln!("{}", tr!("error", error = err.to_string()));
+        exit(3);
+    });
+    i18n::init(config.ui.language);
+
+    let backend = audio_backend_name().unwrap_or_else(|err| {
+        eprintln!("{}", tr!("error", error = err));
+        eprintln!("{}", tr!("tui-usage"));
+        exit(2);
+    });
+
+    let library = SoundLibrary::scan("assets/sounds").unwrap_or_else(|err| {
+        eprintln!("{}", tr!("error", error = err.to_string()));
+        exit(match err {
+            LibraryError::NotFound(_) => 99,
+            _ => 98,
+        });
+    });
+
+    let audio: Box<dyn AudioBackend> = match backend.as_str() {
+        "silent" => Box::new(SilentBackend::default()),
+        "loopback" => Box::new(LoopbackBackend::new()),
+        _ => match RodioBackend::new() {
+            Ok(backend) => Box::new(backend),
+            Err(err) => {
+                eprintln!("{}", tr!("error", error = tr!("tui-no-audio", error = err)));
+                exit(97);
+            }
+        },
+    };
+
+    let mut session = TrainingSession::new(library, config.timings.timings());
+    session.set_copy_behind(config.copy_behind.copy_behind());
+    session.set_limits(config.session.limits());
+    session.set_replay_credit(config.session.replay_credit());
+    let mut app = App::new(session, Profile::load(DEFAULT_PROFILE), audio, config.keys);
+
+    let terminal = ratatui::init();
+    let result = run(terminal, &mut app);
+    ratatui::restore();
+    app.finish();
+
+    if let Err(err) = result {
+        eprintln!("{}", tr!("error", error = err.to_string()));
+        exit(1);
+    }
+}
+
+fn audio_backend_name() -> Result<String, String> {
+    let mut args = env::args().skip(1);
+    let mut backend = String::from("rodio");
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--audio" => backend = args.next().ok_or_else(|| tr!("tui-missing-value"))?,
+            _ => return Err(tr!("tui-unknown-argument", argument = arg)),
+        }
+    }
+
+    match backend.as_str() {
+        "rodio" | "loopback" | "silent" => Ok(backend),
+        _ => Err(tr!("tui-unknown-backend", backend = backend)),
+    }
+}
+
+fn run(mut terminal: DefaultTerminal, app: &mut App) -> io::Result<()> {
+    let mut last_frame = Instant::now();
+
+    while !app.quit {
+        terminal.draw(|frame| ui::draw(frame, app))?;
+
+        if event::poll(FRAME)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            app.handle_key(key);
+        }
+
+        let now = Instant::now();
+        app.update(now - last_frame);
+        last_frame = now;
+    }
+
+    Ok(())
+}
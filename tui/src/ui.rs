@@ -0,0 +1,68 @@
+use cw_engine::{tr, PlayState};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout},
+    style::{Color, Style, Stylize},
+    text::Line,
+    widgets::{Block, Paragraph, Wrap},
+};
+
+use crate::app::App;
+
+pub fn draw(frame: &mut Frame, app: &App) {
+    let [header, status, input, footer] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Min(6),
+        Constraint::Length(3),
+        Constraint::Length(2),
+    ])
+    .areas(frame.area());
+
+    let score = tr!("score", correct = app.trainer.correct_count(), wrong = app.trainer.wrong_count());
+    let [title, score_area] =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(score.chars().count() as u16)]).areas(header);
+    frame.render_widget(Line::from("CW Listen Trainer").bold(), title);
+    frame.render_widget(Line::from(score).fg(Color::Gray), score_area);
+
+    let status_title = format!(" {} ", tr!(if app.trainer.is_paused() { "tui-status-paused" } else { "tui-status" }));
+    let home = tr!("tui-home", key = app.keys.start_stop.as_str());
+    // Ohne laufende Sitzung die Zusammenfassung der letzten bzw. die Grenzen der naechsten
+    let status_text = match (&app.summary, app.trainer.state()) {
+        (Some(summary), PlayState::Stopped) => {
+            format!("{}\n{}\n\n{}\n\n{}", tr!("summary-title"), app.trainer.prompt(), summary, home)
+        }
+        (None, PlayState::Stopped) => format!("{}\n\n{}", home, app.trainer.limits()),
+        _ => app.trainer.prompt().to_string(),
+    };
+    frame.render_widget(
+        Paragraph::new(status_text)
+            .wrap(Wrap { trim: false })
+            .block(Block::bordered().title(status_title)),
+        status,
+    );
+
+    let waiting = app.trainer.state() == PlayState::WaitingForAnswer;
+    let input_style = if waiting { Style::default().fg(Color::Yellow) } else { Style::default().fg(Color::DarkGray) };
+    frame.render_widget(
+        Paragraph::new(app.answer.as_str())
+            .style(input_style)
+            .block(Block::bordered().title(format!(" {} ", tr!("tui-answer")))),
+        input,
+    );
+    if waiting {
+        frame.set_cursor_position((input.x + 1 + app.answer.chars().count() as u16, input.y + 1));
+    }
+
+    let help = tr!(
+        "tui-help",
+        start_stop = app.keys.start_stop.as_str(),
+        pause = app.keys.pause.as_str(),
+        replay = app.keys.replay.as_str(),
+        skip = app.keys.skip.as_str(),
+    );
+    let mut lines = vec![Line::from(help).fg(Color::Gray)];
+    if let Some(error) = app.audio.last_error() {
+        lines.push(Line::from(tr!("audio-error", error = error)).fg(Color::Red));
+    }
+    frame.render_widget(Paragraph::new(lines), footer);
+}